@@ -1,22 +1,68 @@
+use std::sync::{Mutex, PoisonError};
+use std::sync::atomic::AtomicU64;
+use std::time::{Duration, Instant};
+
+use futures::stream::BoxStream;
+use tokio::sync::broadcast;
+use tokio::sync::broadcast::error::RecvError;
 use tokio_tungstenite::connect_async;
+use tokio_tungstenite::tungstenite::protocol::CloseFrame;
+use tokio_tungstenite::tungstenite::protocol::frame::coding::CloseCode;
+use tokio_util::sync::CancellationToken;
 
-use super::helpers::handle_event;
+use super::helpers::{
+    DedupCache, Dispatcher, create_subscriptions, delete_subscription, handle_event,
+    resolve_scopes,
+};
 use super::{
-    Arc, BoxFuture, Client, DateTime, EventMessage, EventType, FutureExt, HashMap, MaybeTlsStream,
-    Message, NotificationEvent, Result, RwLock, StreamExt, TcpStream, UserConfig, Utc,
-    WebSocketStream,
+    Arc, BoxFuture, CallbackId, Client, ControllerHandle, DateTime, DedupConfig, DisconnectReason,
+    DispatchMode, EVENTSUB_WS_URL, Error, EventMessage, EventPayload, EventType, FutureExt,
+    HashMap, KeepaliveStats, MaybeTlsStream, Message, MessageId, NotificationEvent,
+    NotificationMessage, NotificationMetadata, ReconnectAttempt, ReconnectPolicy, RejectedMessage,
+    RejectionReason, ResubscribePolicy, Result, RevocationMessage, RevocationReason, RwLock,
+    StreamExt, Subscription, SubscriptionFailurePolicy, SubscriptionOutcome, SubscriptionRequest,
+    SubscriptionVerdict, TcpStream, UserConfig, Utc, Uuid, WebSocketStream,
 };
+use crate::helix::Condition;
 
 type ArcCallbackMap<S, T> = Arc<RwLock<HashMap<S, T>>>;
-type FutType = dyn Fn(NotificationEvent, DateTime<Utc>) -> BoxFuture<'static, ()> + Send + Sync;
-#[rustfmt::skip]
-type BoxedCallback =Box<dyn Fn(NotificationEvent, DateTime<Utc>) -> BoxFuture<'static, ()> + Send + Sync>;
+type FutType = dyn Fn(&NotificationMessage) -> BoxFuture<'static, ()> + Send + Sync;
+type CallbackList = Vec<(CallbackId, Arc<FutType>)>;
+type ReconnectCallback = Box<dyn Fn(&ReconnectAttempt) + Send + Sync>;
+type RejectionHook = Box<dyn Fn(&RejectedMessage) + Send + Sync>;
+type SubscriptionCallback = Arc<dyn Fn(&SubscriptionOutcome) + Send + Sync>;
+type RevocationCallback = Box<dyn Fn(&RevocationMessage, &RevocationReason) + Send + Sync>;
+type TokenRefresher = Arc<dyn Fn() -> BoxFuture<'static, Result<String>> + Send + Sync>;
+
+/// How long a `session_reconnect` handover may take if the keepalive window
+/// isn't known yet. Twitch gives clients 30 seconds to connect to the
+/// reconnect URL.
+const HANDOVER_TIMEOUT: Duration = Duration::from_secs(30);
+
 pub struct TwitchController {
     ws: WebSocketStream<MaybeTlsStream<TcpStream>>,
     session_id: Arc<RwLock<Option<String>>>,
     http_client: Arc<Client>,
-    user_config: UserConfig,
-    ntfy_callbacks: ArcCallbackMap<EventType, Box<FutType>>,
+    user_config: Arc<RwLock<UserConfig>>,
+    ntfy_callbacks: ArcCallbackMap<EventType, CallbackList>,
+    reconnect_policy: ReconnectPolicy,
+    reconnect_callback: Option<ReconnectCallback>,
+    keepalive: Arc<RwLock<KeepaliveStats>>,
+    keepalive_grace: Duration,
+    dedup: Mutex<DedupCache>,
+    max_message_age: Option<Duration>,
+    rejection_hook: Option<RejectionHook>,
+    events: broadcast::Sender<EventMessage>,
+    dispatcher: Dispatcher,
+    shutdown: CancellationToken,
+    subscriptions: Arc<RwLock<Vec<Uuid>>>,
+    unsubscribe_on_shutdown: bool,
+    subscription_set: Vec<SubscriptionRequest>,
+    subscription_callback: Option<SubscriptionCallback>,
+    subscription_failure_policy: SubscriptionFailurePolicy,
+    revocation_callback: Option<RevocationCallback>,
+    resubscribe_policy: ResubscribePolicy,
+    token_refresher: Option<TokenRefresher>,
 }
 
 impl TwitchController {
@@ -29,99 +75,804 @@ impl TwitchController {
             ws,
             session_id: Arc::new(RwLock::new(None)),
             http_client: Arc::new(client),
-            user_config,
+            user_config: Arc::new(RwLock::new(user_config)),
             ntfy_callbacks: Arc::new(RwLock::new(HashMap::new())),
+            reconnect_policy: ReconnectPolicy::default(),
+            reconnect_callback: None,
+            keepalive: Arc::new(RwLock::new(KeepaliveStats::default())),
+            keepalive_grace: Duration::from_secs(5),
+            dedup: Mutex::new(DedupCache::new(DedupConfig::default())),
+            max_message_age: None,
+            rejection_hook: None,
+            events: broadcast::channel(256).0,
+            dispatcher: Dispatcher::default(),
+            shutdown: CancellationToken::new(),
+            subscriptions: Arc::new(RwLock::new(Vec::new())),
+            unsubscribe_on_shutdown: false,
+            subscription_set: Vec::new(),
+            subscription_callback: None,
+            subscription_failure_policy: SubscriptionFailurePolicy::default(),
+            revocation_callback: None,
+            resubscribe_policy: ResubscribePolicy::default(),
+            token_refresher: None,
         }
     }
 
-    pub async fn register_callback<F, Fut>(&self, event_type: EventType, callback: F)
+    /// Adds a subscription to the set the controller creates after every
+    /// fresh `session_welcome`. The session ID of the request is filled in
+    /// by the controller.
+    ///
+    /// If no subscription was added, the controller subscribes to
+    /// `channel.chat.message` of the channel in its [`UserConfig`].
+    pub fn add_subscription(&mut self, request: SubscriptionRequest) {
+        self.subscription_set.push(request);
+    }
+
+    /// Registers a callback that is invoked with the outcome of every
+    /// subscription the controller creates, including its cost. Only one
+    /// callback is kept, registering again replaces the previous one.
+    pub fn on_subscription_result<F>(&mut self, callback: F)
+    where
+        F: Fn(&SubscriptionOutcome) + Send + Sync + 'static, {
+        self.subscription_callback = Some(Arc::new(callback));
+    }
+
+    /// Decides whether a subscription Twitch refused to create makes
+    /// `start()` fail. Defaults to
+    /// [`SubscriptionFailurePolicy::AbortOnAuthError`].
+    #[must_use]
+    pub const fn with_subscription_failure_policy(
+        mut self,
+        policy: SubscriptionFailurePolicy,
+    ) -> Self {
+        self.subscription_failure_policy = policy;
+        self
+    }
+
+    /// Registers a callback that is invoked when Twitch revokes one of the
+    /// subscriptions, along with the reason. Only one callback is kept,
+    /// registering again replaces the previous one.
+    pub fn on_revocation<F>(&mut self, callback: F)
+    where
+        F: Fn(&RevocationMessage, &RevocationReason) + Send + Sync + 'static, {
+        self.revocation_callback = Some(Box::new(callback));
+    }
+
+    /// Decides whether revoked subscriptions are created again. Defaults to
+    /// [`ResubscribePolicy::Never`].
+    #[must_use]
+    pub const fn with_resubscribe_policy(mut self, policy: ResubscribePolicy) -> Self {
+        self.resubscribe_policy = policy;
+        self
+    }
+
+    /// Sets the function used to obtain a fresh user access token before a
+    /// revoked subscription is created again. The returned token replaces
+    /// the one in the [`UserConfig`] for every later Helix request.
+    #[must_use]
+    pub fn with_token_refresher<F, Fut>(mut self, refresher: F) -> Self
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<String>> + Send + 'static, {
+        self.token_refresher = Some(Arc::new(move || refresher().boxed()));
+        self
+    }
+
+    /// Returns a cloneable handle that can stop the controller and manage its
+    /// subscriptions from another task while `start()` is running.
+    #[must_use]
+    pub fn handle(&self) -> ControllerHandle {
+        ControllerHandle::new(
+            self.shutdown.clone(),
+            Arc::clone(&self.session_id),
+            Arc::clone(&self.http_client),
+            Arc::clone(&self.user_config),
+            Arc::clone(&self.subscriptions),
+        )
+    }
+
+    /// Whether the EventSub subscriptions created by the controller are
+    /// deleted through Helix when it is shut down through its
+    /// [`ControllerHandle`]. Disabled by default.
+    #[must_use]
+    pub const fn with_unsubscribe_on_shutdown(mut self, unsubscribe: bool) -> Self {
+        self.unsubscribe_on_shutdown = unsubscribe;
+        self
+    }
+
+    /// Replaces the policy used to reconnect after the connection drops.
+    /// Defaults to [`ReconnectPolicy::default()`].
+    #[must_use]
+    pub fn with_reconnect_policy(mut self, policy: ReconnectPolicy) -> Self {
+        self.reconnect_policy = policy;
+        self
+    }
+
+    /// Sets how long past the keepalive window announced by Twitch the
+    /// controller waits for a frame before treating the connection as dead.
+    /// Defaults to 5 seconds.
+    #[must_use]
+    pub const fn with_keepalive_grace(mut self, grace: Duration) -> Self {
+        self.keepalive_grace = grace;
+        self
+    }
+
+    /// Replaces the settings of the cache used to drop notifications that
+    /// Twitch delivered more than once. Resets the duplicate counter.
+    #[must_use]
+    pub fn with_dedup(self, config: DedupConfig) -> Self {
+        Self { dedup: Mutex::new(DedupCache::new(config)), ..self }
+    }
+
+    /// Enables replay protection: notifications and revocations whose
+    /// `message_timestamp` is older than `max_age` are dropped instead of
+    /// dispatched. Twitch recommends 10 minutes. Disabled by default.
+    #[must_use]
+    pub const fn with_max_message_age(mut self, max_age: Duration) -> Self {
+        self.max_message_age = Some(max_age);
+        self
+    }
+
+    /// Registers a hook that is invoked for every message dropped as a
+    /// duplicate or for being too old. Only one hook is kept, registering
+    /// again replaces the previous one.
+    pub fn on_rejected_message<F>(&mut self, hook: F)
+    where
+        F: Fn(&RejectedMessage) + Send + Sync + 'static, {
+        self.rejection_hook = Some(Box::new(hook));
+    }
+
+    /// Sets how notification callbacks are run, see [`DispatchMode`].
+    /// Defaults to [`DispatchMode::Inline`].
+    #[must_use]
+    pub fn with_dispatch_mode(self, mode: DispatchMode) -> Self {
+        let handler_timeout: Option<Duration> = self.dispatcher.handler_timeout();
+        Self { dispatcher: Dispatcher::new(mode, handler_timeout), ..self }
+    }
+
+    /// Limits how long a single callback may run. Callbacks exceeding it are
+    /// cancelled with a warning, so the next callback can run. No limit by
+    /// default.
+    #[must_use]
+    pub fn with_handler_timeout(self, timeout: Duration) -> Self {
+        let mode: DispatchMode = self.dispatcher.mode();
+        Self { dispatcher: Dispatcher::new(mode, Some(timeout)), ..self }
+    }
+
+    /// Sets how many messages the event channel buffers for each receiver
+    /// before slow receivers start missing messages, at least 1. Defaults to
+    /// 256. Receivers created before this call are closed.
+    #[must_use]
+    pub fn with_event_capacity(self, capacity: usize) -> Self {
+        Self { events: broadcast::channel(capacity.max(1)).0, ..self }
+    }
+
+    /// Returns a receiver of every parsed message the controller dispatches,
+    /// in addition to the registered callbacks. Duplicate and stale messages
+    /// are never sent, and `session_reconnect` is handled by the controller
+    /// itself.
+    ///
+    /// Receivers that fall more than the configured capacity behind miss the
+    /// oldest messages, see [`broadcast::Receiver::recv()`].
+    #[must_use]
+    pub fn subscribe_events(&self) -> broadcast::Receiver<EventMessage> { self.events.subscribe() }
+
+    /// Same as `subscribe_events()`, but as a [`futures::Stream`], for use
+    /// with `select!` and stream combinators. Messages missed by a lagging
+    /// stream are skipped with a warning. The stream ends when the controller
+    /// is dropped.
+    #[must_use]
+    pub fn event_stream(&self) -> BoxStream<'static, EventMessage> {
+        futures::stream::unfold(self.events.subscribe(), |mut rx| async move {
+            loop {
+                match rx.recv().await {
+                    Ok(msg) => return Some((msg, rx)),
+                    Err(RecvError::Lagged(skipped)) => {
+                        tracing::warn!("Event stream lagged behind, skipped {skipped} message(s)");
+                    }
+                    Err(RecvError::Closed) => return None,
+                }
+            }
+        })
+        .boxed()
+    }
+
+    /// Returns the number of duplicate messages dropped so far. The counter
+    /// can be read from another task while `start()` is running.
+    #[must_use]
+    pub fn duplicates_dropped(&self) -> Arc<AtomicU64> {
+        let dedup = self.dedup.lock().unwrap_or_else(PoisonError::into_inner);
+        dedup.dropped_counter()
+    }
+
+    /// Returns the liveness stats of the current connection. The returned
+    /// handle stays valid across reconnects and can be read from another
+    /// task while `start()` is running.
+    #[must_use]
+    pub fn keepalive_stats(&self) -> Arc<RwLock<KeepaliveStats>> { Arc::clone(&self.keepalive) }
+
+    /// Registers a callback that is invoked after every reconnect attempt,
+    /// successful or not. Only one callback is kept, registering again
+    /// replaces the previous one.
+    pub fn on_reconnect_attempt<F>(&mut self, callback: F)
+    where
+        F: Fn(&ReconnectAttempt) + Send + Sync + 'static, {
+        self.reconnect_callback = Some(Box::new(callback));
+    }
+
+    /// Registers a callback for `event_type`. Several callbacks can be
+    /// registered for the same event type, they are invoked in the order
+    /// they were registered.
+    ///
+    /// Returns an ID that can be passed to `unregister_callback()`.
+    pub async fn register_callback<F, Fut>(&self, event_type: EventType, callback: F) -> CallbackId
     where
         F: Fn(NotificationEvent, DateTime<Utc>) -> Fut + Send + Sync + 'static,
         Fut: std::future::Future<Output = ()> + Send + 'static, {
         // Code block starts here
-        let boxed: BoxedCallback = Box::new(move |msg, dt| callback(msg, dt).boxed());
-        let event_type_str: &String = &event_type.to_string();
+        let callback: Arc<FutType> = Arc::new(move |msg: &NotificationMessage| {
+            callback(msg.payload.event.clone(), msg.metadata.message_timestamp).boxed()
+        });
+
+        self.insert_callback(&[event_type], callback).await
+    }
+
+    /// Registers a callback that receives the typed payload of one kind of
+    /// event, together with the metadata of the message and the subscription
+    /// it was delivered for. The event type is taken from `T`, so there is
+    /// no need to match on [`NotificationEvent`]:
+    ///
+    /// ```ignore
+    /// controller
+    ///     .register_typed_callback(|ccm: ChannelChatMessage, _meta, _sub| async move {
+    ///         println!("{}: {}", ccm.chatter_user_name, ccm.message.text);
+    ///     })
+    ///     .await;
+    /// ```
+    ///
+    /// The arguments are owned clones, since the returned future must be
+    /// `'static`. Payloads shared by several events, like
+    /// [`CustomRewardRedemption`](crate::session::CustomRewardRedemption),
+    /// are delivered for every one of them.
+    pub async fn register_typed_callback<T, F, Fut>(&self, callback: F) -> CallbackId
+    where
+        T: EventPayload,
+        F: Fn(T, NotificationMetadata, Subscription) -> Fut + Send + Sync + 'static,
+        Fut: std::future::Future<Output = ()> + Send + 'static, {
+        let callback: Arc<FutType> = Arc::new(move |msg: &NotificationMessage| {
+            T::from_event(&msg.payload.event).map_or_else(
+                || futures::future::ready(()).boxed(),
+                |event| {
+                    let subscription: Subscription = msg.payload.subscription.clone();
+                    callback(event.clone(), msg.metadata.clone(), subscription).boxed()
+                },
+            )
+        });
+
+        self.insert_callback(T::EVENT_TYPES, callback).await
+    }
+
+    async fn insert_callback(
+        &self,
+        event_types: &[EventType],
+        callback: Arc<FutType>,
+    ) -> CallbackId {
+        let id: CallbackId = CallbackId::next();
+
+        let mut callbacks = self.ntfy_callbacks.write().await;
+        for &event_type in event_types {
+            callbacks.entry(event_type).or_default().push((id, Arc::clone(&callback)));
+            tracing::info!("Registered callback {id} for event type: {event_type}");
+        }
 
-        self.ntfy_callbacks.write().await.insert(event_type, boxed);
-        tracing::info!("Registered callback for event type: {event_type_str}");
+        id
+    }
+
+    /// Removes the callback registered under `id`. Returns `false` if no
+    /// such callback was registered.
+    pub async fn unregister_callback(&self, id: CallbackId) -> bool {
+        // A typed callback can be registered under several event types
+        let mut removed: bool = false;
+        let mut callbacks = self.ntfy_callbacks.write().await;
+        for (event_type, list) in callbacks.iter_mut() {
+            if let Some(index) = list.iter().position(|(cb_id, _)| *cb_id == id) {
+                list.remove(index);
+                tracing::info!("Unregistered callback {id} for event type: {event_type}");
+                removed = true;
+            }
+        }
+
+        removed
     }
 
     /// This function starts the main loop for keeping the connection.
     ///
+    /// If the connection drops, it reconnects to Twitch following the
+    /// configured [`ReconnectPolicy`] and subscribes again on the fresh
+    /// `session_welcome`.
+    ///
+    /// However it returns, the connection is closed and callbacks that are
+    /// still running are awaited first.
+    ///
     /// # Errors
     ///
     /// - Returns `serde_json::Error`, `anyhow::Error`, or `reqwest::Error` if
     ///   the `handle_event()` function fails
+    /// - Returns `tokio_tungstenite::tungstenite::Error` if the reconnect
+    ///   policy ran out of attempts
+    /// - Returns `Error::HelixError` or `Error::TransportError` if a
+    ///   subscription failed in a way the [`SubscriptionFailurePolicy`]
+    ///   aborts on. Requests that could not be sent otherwise make the
+    ///   controller reconnect and subscribe again
     pub async fn start(&mut self) -> Result<()> {
-        let mut is_reconnect: bool = false;
-        while let Some(msg) = self.ws.next().await {
+        let result: Result<()> = self.supervise().await;
+        self.shutdown_gracefully().await;
+        result
+    }
+
+    /// Runs sessions and reconnects until a shutdown is requested, the
+    /// connection is lost with reconnecting disabled, or an error occurs.
+    async fn supervise(&mut self) -> Result<()> {
+        while !self.shutdown.is_cancelled() {
+            let reason: DisconnectReason = self.run_session().await?;
+            if reason == DisconnectReason::ShutdownRequested {
+                break;
+            }
+
+            tracing::warn!("Lost connection to Twitch: {reason}");
+            if !self.reconnect_policy.enabled {
+                break;
+            }
+
+            self.reconnect_with_backoff(&reason).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Closes the connection before `start()` returns and waits for
+    /// callbacks that are still running. If a shutdown was requested, our
+    /// subscriptions are deleted first when configured to.
+    async fn shutdown_gracefully(&mut self) {
+        tracing::info!("Shutting down controller");
+
+        if self.unsubscribe_on_shutdown && self.shutdown.is_cancelled() {
+            let ids: Vec<Uuid> = std::mem::take(&mut *self.subscriptions.write().await);
+            for id in ids {
+                let http_client: Arc<Client> = Arc::clone(&self.http_client);
+                let config: UserConfig = self.user_config.read().await.clone();
+                if let Err(e) = delete_subscription(http_client, id, &config).await {
+                    tracing::warn!("Failed to delete subscription {id}: {e}");
+                }
+            }
+        }
+
+        let frame: CloseFrame = CloseFrame { code: CloseCode::Normal, reason: "shutdown".into() };
+        if let Err(e) = self.ws.close(Some(frame)).await {
+            tracing::debug!("Failed to close connection: {e}");
+        }
+
+        self.dispatcher.drain().await;
+        tracing::info!("Controller shut down");
+    }
+
+    /// Reads from the current connection until it is lost, returning the
+    /// reason it ended.
+    async fn run_session(&mut self) -> Result<DisconnectReason> {
+        let shutdown: CancellationToken = self.shutdown.clone();
+        loop {
+            let window: Option<Duration> = self.keepalive_window().await;
+            let read = async {
+                match window {
+                    Some(window) => {
+                        tokio::time::timeout(window, self.ws.next()).await.map_err(|_| window)
+                    }
+                    None => Ok(self.ws.next().await),
+                }
+            };
+
+            let next = tokio::select! {
+                () = shutdown.cancelled() => return Ok(DisconnectReason::ShutdownRequested),
+                next = read => next,
+            };
+            let next = match next {
+                Ok(next) => next,
+                Err(window) => {
+                    tracing::error!("No frame received within {window:?}, connection is dead");
+                    return Ok(DisconnectReason::KeepaliveTimeout(window));
+                }
+            };
+            let Some(msg) = next else { break };
+
             match msg {
                 Ok(Message::Text(raw)) => {
-                    let sid_clone: Arc<RwLock<Option<String>>> = Arc::clone(&self.session_id);
-                    let http_client: Arc<Client> = Arc::clone(&self.http_client);
-
-                    let msg: EventMessage = handle_event(
-                        raw.as_str(),
-                        sid_clone,
-                        http_client,
-                        &self.user_config,
-                        is_reconnect,
-                    )
-                    .await?;
+                    let msg: EventMessage = self.process_frame(raw.as_str()).await?;
 
-                    if let EventMessage::Reconnect(r) = &msg {
-                        self.reconnect(r.payload.session.reconnect_url.clone()).await?;
-                        is_reconnect = true;
-                    } else {
-                        if let EventMessage::Welcome(_) = &msg {
-                            is_reconnect = false;
+                    if let EventMessage::Welcome(welc_msg) = &msg
+                        && let Some(session) = &welc_msg.payload.session
+                        && let Some(reason) = self.subscribe_all(&session.id).await?
+                    {
+                        let frame: CloseFrame =
+                            CloseFrame { code: CloseCode::Normal, reason: "resubscribe".into() };
+                        if let Err(e) = self.ws.close(Some(frame)).await {
+                            tracing::debug!("Failed to close connection: {e}");
                         }
+                        return Ok(reason);
+                    }
 
+                    if let EventMessage::Reconnect(r) = &msg {
+                        let reconnect_url: String = r.payload.session.reconnect_url.clone();
+                        if let Err(e) = self.reconnect(reconnect_url).await {
+                            tracing::error!("Handover to the reconnect URL failed: {e}");
+                            return Ok(DisconnectReason::HandoverFailed(e.to_string()));
+                        }
+                    } else {
                         self.handle_message(msg).await;
                     }
                 }
                 Ok(Message::Close(frame)) => {
                     tracing::warn!("WebSocket closed: {frame:?}");
+                    let frame: Option<String> = frame.map(|f| format!("{}: {}", f.code, f.reason));
+                    return Ok(DisconnectReason::Closed(frame));
+                }
+                Ok(_) => {
+                    self.keepalive.write().await.touch(Utc::now());
+                }
+                Err(e) => {
+                    tracing::error!("Error in main loop: {e}");
+                    return Ok(DisconnectReason::SocketError(e.to_string()));
                 }
-                Ok(_) => (),
-                Err(e) => tracing::error!("Error in main loop: {e}"),
             }
         }
 
-        Ok(())
+        Ok(DisconnectReason::StreamEnded)
+    }
+
+    /// The time the controller waits for a frame before treating the
+    /// connection as dead, known once `session_welcome` was received.
+    async fn keepalive_window(&self) -> Option<Duration> {
+        let timeout: Option<Duration> = self.keepalive.read().await.keepalive_timeout;
+        timeout.map(|timeout| timeout + self.keepalive_grace)
+    }
+
+    /// Creates the declared subscription set on the session `session_id`,
+    /// reporting the outcome of every subscription. Fails with the first
+    /// failed subscription the failure policy doesn't tolerate, and returns
+    /// why the session must be started over if a request could not be sent.
+    async fn subscribe_all(&self, session_id: &str) -> Result<Option<DisconnectReason>> {
+        let http_client: Arc<Client> = Arc::clone(&self.http_client);
+        let config: UserConfig = resolve_scopes(http_client, &self.user_config).await;
+        let requests: Vec<SubscriptionRequest> = if self.subscription_set.is_empty() {
+            vec![SubscriptionRequest::chat_message(&config.broadcaster_id, &config.user_id)]
+        } else {
+            self.subscription_set.clone()
+        };
+
+        let http_client: Arc<Client> = Arc::clone(&self.http_client);
+        let outcomes: Vec<SubscriptionOutcome> =
+            create_subscriptions(http_client, &config, session_id, &requests).await;
+
+        let mut created: usize = 0;
+        for outcome in &outcomes {
+            if outcome.result.is_ok() {
+                created += 1;
+            }
+            self.record_outcome(outcome).await;
+        }
+
+        tracing::info!("Created {created}/{} subscription(s)", outcomes.len());
+        match self.subscription_failure_policy.verdict(&outcomes) {
+            SubscriptionVerdict::Keep => Ok(None),
+            SubscriptionVerdict::Retry(e) => Ok(Some(DisconnectReason::SubscribeFailed(e))),
+            SubscriptionVerdict::Abort(e) => Err(e.into()),
+        }
+    }
+
+    /// Remembers a created subscription, or logs why it failed, and passes
+    /// the outcome to the subscription callback.
+    async fn record_outcome(&self, outcome: &SubscriptionOutcome) {
+        record_outcome(&self.subscriptions, self.subscription_callback.as_ref(), outcome).await;
+    }
+
+    /// Reports a revoked subscription and creates it again if the resubscribe
+    /// policy allows it. The token refresh and the Helix request run on their
+    /// own task, so reading frames goes on meanwhile.
+    async fn handle_revocation(&self, rev_msg: &RevocationMessage) {
+        let reason: RevocationReason = rev_msg.reason();
+        let revoked: &Subscription = &rev_msg.payload.subscription;
+        tracing::warn!(
+            "Subscription event revoked - type: {}, version: {}, reason: {reason}",
+            rev_msg.metadata.subscription_type,
+            rev_msg.metadata.subscription_version
+        );
+
+        self.subscriptions.write().await.retain(|id| *id != revoked.id);
+        if let Some(callback) = &self.revocation_callback {
+            callback(rev_msg, &reason);
+        }
+
+        if self.resubscribe_policy == ResubscribePolicy::Never || !reason.is_recoverable() {
+            return;
+        }
+
+        if self.token_refresher.is_none() && reason == RevocationReason::AuthorizationRevoked {
+            tracing::warn!("No token refresher set, not resubscribing to {}", revoked.sub_type);
+            return;
+        }
+
+        let resubscription: Resubscription = Resubscription {
+            revoked: revoked.clone(),
+            token_refresher: self.token_refresher.clone(),
+            session_id: Arc::clone(&self.session_id),
+            http_client: Arc::clone(&self.http_client),
+            user_config: Arc::clone(&self.user_config),
+            subscriptions: Arc::clone(&self.subscriptions),
+            subscription_callback: self.subscription_callback.clone(),
+        };
+
+        // A shutdown abandons the resubscribe, so draining never waits on a
+        // hung token refresh
+        let shutdown: CancellationToken = self.shutdown.clone();
+        self.dispatcher.spawn(
+            async move {
+                tokio::select! {
+                    () = shutdown.cancelled() => (),
+                    () = resubscription.run() => (),
+                }
+            }
+            .boxed(),
+        );
+    }
+
+    /// Parses a text frame and updates the session and keepalive state from
+    /// it. The returned message is not dispatched to callbacks yet.
+    async fn process_frame(&self, raw: &str) -> Result<EventMessage> {
+        let now: DateTime<Utc> = Utc::now();
+        let previous_frame: Option<DateTime<Utc>> = self.keepalive.write().await.touch(now);
+
+        let sid_clone: Arc<RwLock<Option<String>>> = Arc::clone(&self.session_id);
+        let msg: EventMessage = handle_event(raw, sid_clone).await?;
+
+        match &msg {
+            EventMessage::Welcome(welc_msg) => {
+                let Some(session) = &welc_msg.payload.session else {
+                    return Err(Error::NoneError("session_welcome without a session".into()));
+                };
+
+                let timeout: Duration =
+                    Duration::from_secs(session.keepalive_timeout_seconds.into());
+                self.keepalive.write().await.keepalive_timeout = Some(timeout);
+            }
+            EventMessage::Keepalive(_) => {
+                self.keepalive.write().await.record_keepalive(previous_frame, now);
+            }
+            _ => (),
+        }
+
+        Ok(msg)
     }
 
-    /// Reconnects with a new WebSocket connection, replacing the original
-    /// dropped connection
+    /// Connects to [`EVENTSUB_WS_URL`] again, waiting between attempts as
+    /// the [`ReconnectPolicy`] dictates. The session ID is cleared, so the
+    /// next `session_welcome` subscribes to events again.
+    async fn reconnect_with_backoff(&mut self, reason: &DisconnectReason) -> Result<()> {
+        let mut attempt: u32 = 0;
+        loop {
+            attempt += 1;
+            let delay: Duration = self.reconnect_policy.delay_for(attempt);
+            tracing::info!("Reconnect attempt {attempt} in {delay:?}");
+            tokio::select! {
+                () = self.shutdown.cancelled() => return Ok(()),
+                () = tokio::time::sleep(delay) => (),
+            }
+
+            let result = connect_async(EVENTSUB_WS_URL).await;
+            let report: ReconnectAttempt = ReconnectAttempt {
+                attempt,
+                delay,
+                reason: reason.clone(),
+                error: result.as_ref().err().map(ToString::to_string),
+            };
+
+            if let Some(callback) = &self.reconnect_callback {
+                callback(&report);
+            }
+
+            match result {
+                Ok((ws_stream, _)) => {
+                    self.ws = ws_stream;
+                    *self.session_id.write().await = None;
+                    self.subscriptions.write().await.clear();
+                    *self.keepalive.write().await = KeepaliveStats::default();
+                    tracing::info!("Reconnected to Twitch after {attempt} attempt(s)");
+                    return Ok(());
+                }
+                Err(e) => {
+                    tracing::warn!("Reconnect attempt {attempt} failed: {e}");
+                    if self.reconnect_policy.max_attempts.is_some_and(|max| attempt >= max) {
+                        tracing::error!("Giving up reconnecting after {attempt} attempt(s)");
+                        return Err(e.into());
+                    }
+                }
+            }
+        }
+    }
+
+    /// Hands the session over to the connection at `reconnect_url`, as
+    /// requested by a `session_reconnect` message.
+    ///
+    /// The old connection is kept open and read alongside the new one until
+    /// the new connection sends `session_welcome`, so notifications still in
+    /// flight on the old connection are dispatched as well. Only then the
+    /// session ID is switched and the old connection is closed. If a shutdown
+    /// is requested meanwhile, the new connection is dropped and the old one
+    /// is kept. Time spent dispatching old-connection messages to callbacks
+    /// doesn't count against the keepalive window.
     ///
     /// # Errors
     ///
     /// - Returns `tokio_tungstenite::error::Error` if failed to reconnect to
-    ///   the new URL given by the Twitch API
+    ///   the new URL given by the Twitch API, or if the new connection failed
+    ///   before sending `session_welcome`
+    /// - Returns `Error::NoneError` if the new connection didn't send
+    ///   `session_welcome` within the keepalive window
+    /// - Returns `serde_json::Error` if a frame received during the handover
+    ///   could not be parsed
     pub async fn reconnect(&mut self, reconnect_url: String) -> Result<()> {
         tracing::info!("Reconnecting to {reconnect_url}");
-        let (ws_stream, _) = connect_async(reconnect_url).await?;
-        self.ws = ws_stream;
+        let window: Duration = self.keepalive_window().await.unwrap_or(HANDOVER_TIMEOUT);
+        let timed_out =
+            || Error::NoneError(format!("No session_welcome received within {window:?}"));
+        let shutdown: CancellationToken = self.shutdown.clone();
+        let mut deadline = std::pin::pin!(tokio::time::sleep(window));
+
+        let (mut new_ws, _) = tokio::select! {
+            () = shutdown.cancelled() => return Ok(()),
+            () = &mut deadline => return Err(timed_out()),
+            connected = connect_async(reconnect_url) => connected?,
+        };
+        let mut old_open: bool = true;
+
+        loop {
+            let (from_new, frame) = tokio::select! {
+                () = shutdown.cancelled() => {
+                    tracing::info!("Shutdown requested during handover, keeping old connection");
+                    if let Err(e) = new_ws.close(None).await {
+                        tracing::debug!("Failed to close new connection: {e}");
+                    }
+                    return Ok(());
+                }
+                () = &mut deadline => return Err(timed_out()),
+                frame = self.ws.next(), if old_open => (false, frame),
+                frame = new_ws.next() => (true, frame),
+            };
+
+            match (from_new, frame) {
+                (false, Some(Ok(Message::Text(raw)))) => {
+                    let started: tokio::time::Instant = tokio::time::Instant::now();
+                    match self.process_frame(raw.as_str()).await? {
+                        EventMessage::Reconnect(_) => {
+                            tracing::warn!("Ignoring session_reconnect received during handover");
+                        }
+                        msg => self.handle_message(msg).await,
+                    }
+
+                    // Slow callbacks must not make the new connection time out
+                    let extended: tokio::time::Instant = deadline.deadline() + started.elapsed();
+                    deadline.as_mut().reset(extended);
+                }
+                (false, Some(Ok(Message::Close(_)) | Err(_)) | None) => {
+                    tracing::debug!("Old connection ended during handover");
+                    old_open = false;
+                }
+                (true, Some(Ok(Message::Text(raw)))) => {
+                    let msg: EventMessage = self.process_frame(raw.as_str()).await?;
+                    let is_welcome: bool = matches!(msg, EventMessage::Welcome(_));
+                    self.handle_message(msg).await;
+
+                    if is_welcome {
+                        break;
+                    }
+                }
+                (true, Some(Err(e))) => return Err(e.into()),
+                (true, Some(Ok(Message::Close(frame)))) => {
+                    let reason: String = frame.map_or_else(String::new, |f| f.reason.to_string());
+                    return Err(Error::NoneError(format!(
+                        "Reconnect connection closed before session_welcome: {reason}"
+                    )));
+                }
+                (true, None) => {
+                    return Err(Error::NoneError(
+                        "Reconnect connection ended before session_welcome".into(),
+                    ));
+                }
+                (_, Some(Ok(_))) => (),
+            }
+        }
+
+        let mut old_ws: WebSocketStream<MaybeTlsStream<TcpStream>> =
+            std::mem::replace(&mut self.ws, new_ws);
+        if old_open && let Err(e) = old_ws.close(None).await {
+            tracing::debug!("Failed to close old connection: {e}");
+        }
+
         tracing::info!("Reconnected successfully");
         Ok(())
     }
 
+    /// Checks a message against the replay protection (maximum age) and the
+    /// dedup cache, returning why it must be dropped, if it must.
+    fn check_delivery(
+        &self,
+        message_id: &MessageId,
+        message_timestamp: DateTime<Utc>,
+    ) -> Option<RejectionReason> {
+        if let Some(max_age) = self.max_message_age {
+            let age: Duration = (Utc::now() - message_timestamp).to_std().unwrap_or_default();
+            if age > max_age {
+                return Some(RejectionReason::Stale { age });
+            }
+        }
+
+        let mut dedup = self.dedup.lock().unwrap_or_else(PoisonError::into_inner);
+        let first: bool = dedup.check(message_id, Instant::now());
+        if first { None } else { Some(RejectionReason::Duplicate) }
+    }
+
+    /// Returns `true` if the message may be dispatched, otherwise reports
+    /// it to the rejection hook.
+    fn accept_message(
+        &self,
+        message_id: &MessageId,
+        message_type: &str,
+        message_timestamp: DateTime<Utc>,
+    ) -> bool {
+        let Some(reason) = self.check_delivery(message_id, message_timestamp) else {
+            return true;
+        };
+
+        tracing::warn!("Dropped {reason} {message_type} message: {message_id}");
+        if let Some(hook) = &self.rejection_hook {
+            hook(&RejectedMessage {
+                message_id: message_id.clone(),
+                message_type: message_type.to_string(),
+                message_timestamp,
+                reason,
+            });
+        }
+
+        false
+    }
+
     async fn handle_message(&self, msg: EventMessage) {
+        let accepted: bool = match &msg {
+            EventMessage::Notification(ntf_msg) => self.accept_message(
+                &ntf_msg.metadata.message_id,
+                &ntf_msg.metadata.message_type,
+                ntf_msg.metadata.message_timestamp,
+            ),
+            EventMessage::Revocation(rev_msg) => self.accept_message(
+                &rev_msg.metadata.message_id,
+                &rev_msg.metadata.message_type,
+                rev_msg.metadata.message_timestamp,
+            ),
+            _ => true,
+        };
+
+        if !accepted {
+            return;
+        }
+
+        if self.events.receiver_count() > 0 {
+            // Only fails if every receiver was dropped in the meantime
+            let _ = self.events.send(msg.clone());
+        }
+
         match msg {
             EventMessage::Notification(ntf_msg) => {
-                self.handle_notification_event(
-                    ntf_msg.payload.event,
-                    ntf_msg.metadata.message_timestamp,
-                )
-                .await;
-            }
-            EventMessage::Revocation(rev_msg) => {
-                tracing::warn!(
-                    "Subscription event revoked - type: {}, version: {}",
-                    rev_msg.metadata.subscription_type,
-                    rev_msg.metadata.subscription_version
-                );
+                self.handle_notification_event(&ntf_msg).await;
             }
+            EventMessage::Revocation(rev_msg) => self.handle_revocation(&rev_msg).await,
             EventMessage::Welcome(welc_msg) => {
                 let session_id: crate::prelude::welcome::WelcomeSession =
                     welc_msg.payload.session.unwrap();
@@ -132,17 +883,104 @@ impl TwitchController {
         }
     }
 
-    async fn handle_notification_event(&self, event: NotificationEvent, dt: DateTime<Utc>) {
-        match event {
-            NotificationEvent::ChannelChatMessage(ccm) => {
-                if let Some(cb) = self.ntfy_callbacks.read().await.get(&EventType::ChatMessage) {
-                    cb(NotificationEvent::ChannelChatMessage(ccm), dt).await;
-                } else {
-                    let msg: &str = "NotificationEvent was ChannelChatMessage, but there was no callback for it";
-                    tracing::error!("{msg}");
+    async fn handle_notification_event(&self, ntf_msg: &NotificationMessage) {
+        let Some(event_type) = ntf_msg.payload.subscription.sub_type.event_type() else {
+            return;
+        };
+
+        // Callbacks of the event's family run after its own, e.g. every
+        // subscription event reaches the `EventType::Subscription` callbacks
+        let family: EventType = event_type.family();
+        let keys = std::iter::once(event_type).chain((family != event_type).then_some(family));
+
+        // The futures are created under the lock, but only awaited after it
+        // was released
+        let callbacks = self.ntfy_callbacks.read().await;
+        let handlers: Vec<BoxFuture<'static, ()>> = keys
+            .filter_map(|key| callbacks.get(&key))
+            .flatten()
+            .map(|(_, cb)| cb(ntf_msg))
+            .collect();
+        drop(callbacks);
+
+        if handlers.is_empty() {
+            // Stream consumers may be the only ones interested in the event
+            if self.events.receiver_count() == 0 {
+                tracing::warn!("NotificationEvent was {event_type}, but nothing consumed it");
+            }
+            return;
+        }
+
+        let job: BoxFuture<'static, ()> = self.dispatcher.job(event_type, handlers);
+        self.dispatcher.dispatch(family, job).await;
+    }
+}
+
+/// Records a created subscription or logs why it failed, then reports the
+/// outcome to the subscription callback.
+async fn record_outcome(
+    subscriptions: &RwLock<Vec<Uuid>>,
+    callback: Option<&SubscriptionCallback>,
+    outcome: &SubscriptionOutcome,
+) {
+    match &outcome.result {
+        Ok(sub) => subscriptions.write().await.push(sub.subscription.id),
+        Err(e) => tracing::error!("Failed to subscribe to {}: {e}", outcome.request.sub_type),
+    }
+
+    if let Some(callback) = callback {
+        callback(outcome);
+    }
+}
+
+/// Everything needed to create a revoked subscription again, away from the
+/// task reading frames.
+struct Resubscription {
+    revoked: Subscription,
+    token_refresher: Option<TokenRefresher>,
+    session_id: Arc<RwLock<Option<String>>>,
+    http_client: Arc<Client>,
+    user_config: Arc<RwLock<UserConfig>>,
+    subscriptions: Arc<RwLock<Vec<Uuid>>>,
+    subscription_callback: Option<SubscriptionCallback>,
+}
+
+impl Resubscription {
+    async fn run(self) {
+        let revoked: &Subscription = &self.revoked;
+        if let Some(refresher) = &self.token_refresher {
+            match refresher().await {
+                Ok(token) => {
+                    let mut config = self.user_config.write().await;
+                    config.user_token = token;
+                    // The new token may have been granted other scopes
+                    config.scopes = None;
+                }
+                Err(e) => {
+                    tracing::error!("Token refresh failed, not resubscribing: {e}");
+                    return;
                 }
             }
-            NotificationEvent::Other(_) => (),
+        }
+
+        let Some(session_id) = self.session_id.read().await.clone() else {
+            return;
+        };
+
+        let condition: Condition = Condition::Custom(revoked.condition.clone());
+        let request: SubscriptionRequest = SubscriptionRequest::new(
+            revoked.sub_type.as_str(),
+            revoked.version.as_str(),
+            condition,
+        );
+        let http_client: Arc<Client> = Arc::clone(&self.http_client);
+        let config: UserConfig = resolve_scopes(http_client, &self.user_config).await;
+
+        let outcomes: Vec<SubscriptionOutcome> =
+            create_subscriptions(self.http_client, &config, &session_id, &[request]).await;
+        for outcome in &outcomes {
+            let callback: Option<&SubscriptionCallback> = self.subscription_callback.as_ref();
+            record_outcome(&self.subscriptions, callback, outcome).await;
         }
     }
 }
@@ -0,0 +1,93 @@
+use tokio_util::sync::CancellationToken;
+
+use super::helpers::resolve_scopes;
+use super::{Arc, Client, Error, Result, RwLock, SubscriptionRequest, UserConfig, Uuid};
+use crate::helix::{CreatedSubscription, delete_subscription, subscribe};
+
+/// A cloneable handle to control a running `TwitchController` from other
+/// tasks, obtained through `TwitchController::handle()`.
+#[derive(Debug, Clone)]
+pub struct ControllerHandle {
+    shutdown: CancellationToken,
+    session_id: Arc<RwLock<Option<String>>>,
+    http_client: Arc<Client>,
+    user_config: Arc<RwLock<UserConfig>>,
+    subscriptions: Arc<RwLock<Vec<Uuid>>>,
+}
+
+impl ControllerHandle {
+    pub(crate) const fn new(
+        shutdown: CancellationToken,
+        session_id: Arc<RwLock<Option<String>>>,
+        http_client: Arc<Client>,
+        user_config: Arc<RwLock<UserConfig>>,
+        subscriptions: Arc<RwLock<Vec<Uuid>>>,
+    ) -> Self {
+        Self { shutdown, session_id, http_client, user_config, subscriptions }
+    }
+
+    /// Asks the controller to shut down. `start()` stops reading events,
+    /// closes the WebSocket with a Close frame, deletes the subscriptions it
+    /// created if configured to, waits for in-flight callbacks and then
+    /// returns `Ok(())`.
+    ///
+    /// Shutting down is permanent, calling `start()` again returns
+    /// immediately.
+    pub fn shutdown(&self) { self.shutdown.cancel(); }
+
+    /// Whether a shutdown was requested.
+    #[must_use]
+    pub fn is_shutdown(&self) -> bool { self.shutdown.is_cancelled() }
+
+    /// The ID of the current WebSocket session, `None` until the first
+    /// `session_welcome` was received.
+    pub async fn session_id(&self) -> Option<String> { self.session_id.read().await.clone() }
+
+    /// Creates a subscription on the current session while the controller
+    /// is running. The session ID of the request is filled in.
+    ///
+    /// The subscription lives as long as the session: Twitch carries it over
+    /// a `session_reconnect`, but it is not created again after the
+    /// controller had to reconnect on its own. Use
+    /// `TwitchController::add_subscription()` for subscriptions that should
+    /// always exist.
+    ///
+    /// # Errors
+    ///
+    /// - Returns `Error::NoneError` if there is no session yet
+    /// - Returns `Error::MissingScopes` if the token lacks a scope the
+    ///   subscription type requires, without sending the request
+    /// - Returns `Error::HelixError` if Twitch refused to create it
+    /// - Returns `reqwest::Error` if the request to Helix failed
+    pub async fn subscribe(&self, request: SubscriptionRequest) -> Result<CreatedSubscription> {
+        let session_id: String = self
+            .session_id()
+            .await
+            .ok_or_else(|| Error::NoneError("Tried to subscribe without a session".into()))?;
+
+        let request: SubscriptionRequest = request.with_session_id(session_id);
+        let http_client: Arc<Client> = Arc::clone(&self.http_client);
+        let config: UserConfig = resolve_scopes(Arc::clone(&http_client), &self.user_config).await;
+        let created: CreatedSubscription = subscribe(http_client, &config, &request).await?;
+
+        self.subscriptions.write().await.push(created.subscription.id);
+        Ok(created)
+    }
+
+    /// Deletes the subscription with the given ID while the controller is
+    /// running.
+    ///
+    /// # Errors
+    ///
+    /// - Returns `Error::HelixError` if Twitch refused to delete it
+    /// - Returns `reqwest::Error` if the request to Helix failed
+    /// - Returns `url::ParseError` if the request URL could not be built
+    pub async fn unsubscribe(&self, subscription_id: Uuid) -> Result<()> {
+        let http_client: Arc<Client> = Arc::clone(&self.http_client);
+        let config: UserConfig = self.user_config.read().await.clone();
+        delete_subscription(http_client, subscription_id, &config).await?;
+
+        self.subscriptions.write().await.retain(|id| *id != subscription_id);
+        Ok(())
+    }
+}
@@ -0,0 +1,159 @@
+use std::time::Duration;
+
+/// Controls how `TwitchController` re-establishes the WebSocket connection
+/// after it was dropped without Twitch asking for it (network errors, close
+/// frames, the stream ending).
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReconnectPolicy {
+    /// Whether the controller should reconnect at all. If `false`,
+    /// `start()` returns as soon as the connection ends.
+    pub enabled: bool,
+
+    /// Delay before the first reconnect attempt.
+    pub initial_delay: Duration,
+
+    /// Upper bound for the delay between two attempts.
+    pub max_delay: Duration,
+
+    /// Factor the delay is multiplied by after every failed attempt.
+    pub multiplier: f64,
+
+    /// Fraction of the delay that is randomized, clamped to `0.0..=1.0`.
+    /// For example `0.2` turns a 10s delay into anything between 8s and 12s.
+    pub jitter: f64,
+
+    /// Maximum number of consecutive attempts, `None` retries forever.
+    pub max_attempts: Option<u32>,
+}
+
+impl Default for ReconnectPolicy {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            initial_delay: Duration::from_secs(1),
+            max_delay: Duration::from_secs(60),
+            multiplier: 2.0,
+            jitter: 0.2,
+            max_attempts: None,
+        }
+    }
+}
+
+impl ReconnectPolicy {
+    /// A policy that never reconnects, restoring the old behavior of
+    /// `start()` returning once the connection ends.
+    #[must_use]
+    pub fn disabled() -> Self { Self { enabled: false, ..Self::default() } }
+
+    /// Computes the delay to wait before the given attempt, starting at 1.
+    #[must_use]
+    pub fn delay_for(&self, attempt: u32) -> Duration {
+        let exponent: i32 = i32::try_from(attempt.saturating_sub(1)).unwrap_or(i32::MAX);
+        let max_secs: f64 = self.max_delay.as_secs_f64();
+        let base_secs: f64 =
+            (self.initial_delay.as_secs_f64() * self.multiplier.powi(exponent)).min(max_secs);
+
+        let jitter: f64 = self.jitter.clamp(0.0, 1.0);
+        let factor: f64 =
+            if jitter > 0.0 { 1.0 + rand::random_range(-jitter..=jitter) } else { 1.0 };
+
+        Duration::from_secs_f64((base_secs * factor).clamp(0.0, max_secs))
+    }
+}
+
+/// Why the connection to Twitch was lost.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DisconnectReason {
+    /// The server sent a Close frame, with its code and reason if present.
+    Closed(Option<String>),
+    /// Moving the session to the URL from `session_reconnect` failed.
+    HandoverFailed(String),
+    /// No frame arrived within the keepalive window.
+    KeepaliveTimeout(Duration),
+    /// Reading from the socket failed.
+    SocketError(String),
+    /// A shutdown was requested through a `ControllerHandle`.
+    ShutdownRequested,
+    /// A subscription request could not be sent after `session_welcome`, so
+    /// the session is started again to create the subscriptions.
+    SubscribeFailed(String),
+    /// The stream ended without a Close frame.
+    StreamEnded,
+}
+
+impl std::fmt::Display for DisconnectReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Closed(Some(frame)) => write!(f, "connection closed ({frame})"),
+            Self::Closed(None) => write!(f, "connection closed"),
+            Self::HandoverFailed(e) => write!(f, "session handover failed: {e}"),
+            Self::KeepaliveTimeout(window) => write!(f, "no frame received within {window:?}"),
+            Self::SocketError(e) => write!(f, "socket error: {e}"),
+            Self::ShutdownRequested => write!(f, "shutdown requested"),
+            Self::SubscribeFailed(e) => write!(f, "subscribing failed: {e}"),
+            Self::StreamEnded => write!(f, "stream ended"),
+        }
+    }
+}
+
+/// Describes a single reconnect attempt, passed to the callback registered
+/// with `TwitchController::on_reconnect_attempt()`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReconnectAttempt {
+    /// Number of this attempt, starting at 1.
+    pub attempt: u32,
+
+    /// How long the controller waited before this attempt.
+    pub delay: Duration,
+
+    /// Why the previous connection was lost.
+    pub reason: DisconnectReason,
+
+    /// The connection error if this attempt failed, `None` on success.
+    pub error: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::ReconnectPolicy;
+
+    fn without_jitter() -> ReconnectPolicy { ReconnectPolicy { jitter: 0.0, ..Default::default() } }
+
+    #[test]
+    fn grows_exponentially() {
+        let policy: ReconnectPolicy = without_jitter();
+
+        assert_eq!(policy.delay_for(1), Duration::from_secs(1));
+        assert_eq!(policy.delay_for(2), Duration::from_secs(2));
+        assert_eq!(policy.delay_for(4), Duration::from_secs(8));
+    }
+
+    #[test]
+    fn caps_at_max_delay() {
+        let policy: ReconnectPolicy = without_jitter();
+
+        assert_eq!(policy.delay_for(7), Duration::from_secs(60));
+        assert_eq!(policy.delay_for(u32::MAX), Duration::from_secs(60));
+    }
+
+    #[test]
+    fn jitter_stays_in_bounds() {
+        let policy: ReconnectPolicy = ReconnectPolicy { jitter: 0.2, ..Default::default() };
+
+        for _ in 0..100 {
+            let delay: Duration = policy.delay_for(4);
+            assert!(delay >= Duration::from_secs_f64(6.4) && delay <= Duration::from_secs_f64(9.6));
+        }
+    }
+
+    #[test]
+    fn jitter_never_exceeds_max_delay() {
+        let policy: ReconnectPolicy = ReconnectPolicy { jitter: 1.0, ..Default::default() };
+
+        for _ in 0..100 {
+            assert!(policy.delay_for(10) <= policy.max_delay);
+        }
+    }
+}
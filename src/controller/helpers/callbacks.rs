@@ -0,0 +1,18 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Identifies a callback registered on `TwitchController`, returned by
+/// `register_callback()` and used to unregister it again.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct CallbackId(u64);
+
+impl CallbackId {
+    /// Hands out a new ID, unique for the lifetime of the process.
+    pub(crate) fn next() -> Self {
+        static NEXT_ID: AtomicU64 = AtomicU64::new(1);
+        Self(NEXT_ID.fetch_add(1, Ordering::Relaxed))
+    }
+}
+
+impl std::fmt::Display for CallbackId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result { write!(f, "#{}", self.0) }
+}
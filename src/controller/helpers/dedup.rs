@@ -0,0 +1,145 @@
+use std::collections::{HashMap, VecDeque};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
+use super::super::MessageId;
+
+/// Settings for the cache the controller uses to drop notifications Twitch
+/// delivered more than once.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DedupConfig {
+    /// Maximum number of message IDs remembered at once. When full, the
+    /// oldest ID is forgotten first. A capacity of `0` disables
+    /// deduplication.
+    pub capacity: usize,
+
+    /// How long a message ID is remembered after it was first seen.
+    pub ttl: Duration,
+}
+
+impl Default for DedupConfig {
+    fn default() -> Self { Self { capacity: 1024, ttl: Duration::from_secs(10 * 60) } }
+}
+
+impl DedupConfig {
+    /// A config that lets every message through, including duplicates.
+    #[must_use]
+    pub const fn disabled() -> Self { Self { capacity: 0, ttl: Duration::ZERO } }
+}
+
+/// A bounded, time-windowed set of recently seen message IDs.
+#[derive(Debug)]
+pub struct DedupCache {
+    config: DedupConfig,
+    seen: HashMap<MessageId, Instant>,
+    order: VecDeque<(MessageId, Instant)>,
+    dropped: Arc<AtomicU64>,
+}
+
+impl DedupCache {
+    #[must_use]
+    pub fn new(config: DedupConfig) -> Self {
+        Self {
+            config,
+            seen: HashMap::new(),
+            order: VecDeque::new(),
+            dropped: Arc::new(AtomicU64::new(0)),
+        }
+    }
+
+    /// Records `id` as seen at `now`. Returns `false` if it was already seen
+    /// within the TTL, in which case the message should be dropped.
+    pub fn check(&mut self, id: &MessageId, now: Instant) -> bool {
+        if self.config.capacity == 0 {
+            return true;
+        }
+
+        self.evict_expired(now);
+        if self.seen.contains_key(id) {
+            self.dropped.fetch_add(1, Ordering::Relaxed);
+            return false;
+        }
+
+        while self.order.len() >= self.config.capacity {
+            if let Some((oldest, _)) = self.order.pop_front() {
+                self.seen.remove(&oldest);
+            }
+        }
+
+        self.seen.insert(id.clone(), now);
+        self.order.push_back((id.clone(), now));
+        true
+    }
+
+    /// The counter of duplicates dropped since the cache was created.
+    #[must_use]
+    pub fn dropped_counter(&self) -> Arc<AtomicU64> { Arc::clone(&self.dropped) }
+
+    fn evict_expired(&mut self, now: Instant) {
+        while let Some((id, seen_at)) = self.order.front() {
+            if now.duration_since(*seen_at) < self.config.ttl {
+                break;
+            }
+
+            self.seen.remove(id);
+            self.order.pop_front();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::Ordering;
+    use std::time::{Duration, Instant};
+
+    use super::{DedupCache, DedupConfig, MessageId};
+
+    fn id(id: &str) -> MessageId { MessageId::StringId(id.to_string()) }
+
+    #[test]
+    fn drops_duplicates_within_ttl() {
+        let mut cache: DedupCache =
+            DedupCache::new(DedupConfig { capacity: 8, ttl: Duration::from_secs(10) });
+        let now: Instant = Instant::now();
+
+        assert!(cache.check(&id("a"), now));
+        assert!(!cache.check(&id("a"), now + Duration::from_secs(9)));
+        assert!(cache.check(&id("b"), now + Duration::from_secs(9)));
+        assert_eq!(cache.dropped_counter().load(Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn forgets_ids_after_ttl() {
+        let mut cache: DedupCache =
+            DedupCache::new(DedupConfig { capacity: 8, ttl: Duration::from_secs(10) });
+        let now: Instant = Instant::now();
+
+        assert!(cache.check(&id("a"), now));
+        assert!(cache.check(&id("a"), now + Duration::from_secs(10)));
+        assert_eq!(cache.dropped_counter().load(Ordering::Relaxed), 0);
+    }
+
+    #[test]
+    fn evicts_oldest_when_full() {
+        let mut cache: DedupCache =
+            DedupCache::new(DedupConfig { capacity: 2, ttl: Duration::from_secs(60) });
+        let now: Instant = Instant::now();
+
+        assert!(cache.check(&id("a"), now));
+        assert!(cache.check(&id("b"), now));
+        assert!(cache.check(&id("c"), now));
+        assert!(cache.check(&id("a"), now));
+        assert!(!cache.check(&id("c"), now));
+    }
+
+    #[test]
+    fn disabled_lets_everything_through() {
+        let mut cache: DedupCache = DedupCache::new(DedupConfig::disabled());
+        let now: Instant = Instant::now();
+
+        assert!(cache.check(&id("a"), now));
+        assert!(cache.check(&id("a"), now));
+        assert_eq!(cache.dropped_counter().load(Ordering::Relaxed), 0);
+    }
+}
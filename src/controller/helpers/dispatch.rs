@@ -0,0 +1,143 @@
+use std::hash::{BuildHasher, RandomState};
+use std::sync::{Mutex, PoisonError};
+use std::time::Duration;
+
+use tokio::sync::mpsc;
+use tokio_util::task::TaskTracker;
+
+use super::super::{BoxFuture, EventType, FutureExt};
+
+/// How the controller runs the callbacks registered for a notification.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DispatchMode {
+    /// Callbacks are awaited one after another before the next frame is
+    /// read. Simple and strictly ordered, but a slow callback delays
+    /// everything else, including keepalive processing.
+    #[default]
+    Inline,
+
+    /// Every notification gets its own task, running its callbacks one after
+    /// another. Nothing is blocked, but notifications may be handled out of
+    /// order.
+    Spawn,
+
+    /// Notifications are queued to a fixed number of worker tasks. All
+    /// notifications of the same event type go to the same worker, so they
+    /// are handled in the order they were received.
+    WorkerPool {
+        /// Number of worker tasks, at least 1.
+        workers: usize,
+
+        /// Number of notifications each worker queues before the controller
+        /// waits for it to catch up, at least 1.
+        queue_capacity: usize,
+    },
+}
+
+type Job = BoxFuture<'static, ()>;
+
+/// Runs dispatch jobs according to a [`DispatchMode`].
+#[derive(Debug, Default)]
+pub struct Dispatcher {
+    mode: DispatchMode,
+    handler_timeout: Option<Duration>,
+    workers: Mutex<Option<Vec<mpsc::Sender<Job>>>>,
+    tasks: TaskTracker,
+    hasher: RandomState,
+}
+
+impl Dispatcher {
+    #[must_use]
+    pub fn new(mode: DispatchMode, handler_timeout: Option<Duration>) -> Self {
+        Self { mode, handler_timeout, ..Self::default() }
+    }
+
+    #[must_use]
+    pub const fn mode(&self) -> DispatchMode { self.mode }
+
+    #[must_use]
+    pub const fn handler_timeout(&self) -> Option<Duration> { self.handler_timeout }
+
+    /// Wraps the futures returned by the callbacks of one notification into
+    /// a single job that runs them in order, each bounded by the handler
+    /// timeout.
+    #[must_use]
+    pub fn job(&self, event_type: EventType, handlers: Vec<BoxFuture<'static, ()>>) -> Job {
+        let handler_timeout: Option<Duration> = self.handler_timeout;
+        async move {
+            for handler in handlers {
+                let Some(limit) = handler_timeout else {
+                    handler.await;
+                    continue;
+                };
+
+                if tokio::time::timeout(limit, handler).await.is_err() {
+                    tracing::warn!("Callback for {event_type} timed out after {limit:?}");
+                }
+            }
+        }
+        .boxed()
+    }
+
+    /// Runs `job`, either right away or on another task depending on the
+    /// dispatch mode.
+    pub async fn dispatch(&self, event_type: EventType, job: Job) {
+        match self.mode {
+            DispatchMode::Inline => job.await,
+            DispatchMode::Spawn => {
+                self.tasks.spawn(job);
+            }
+            DispatchMode::WorkerPool { workers, queue_capacity } => {
+                // Clone the sender out, the lock must not be held across the await
+                let (index, sender) = {
+                    let mut guard = self.workers.lock().unwrap_or_else(PoisonError::into_inner);
+                    let senders: &mut Vec<mpsc::Sender<Job>> = guard
+                        .get_or_insert_with(|| self.spawn_workers(workers, queue_capacity));
+
+                    let index: usize = self.worker_index(event_type, senders.len());
+                    (index, senders[index].clone())
+                };
+
+                if let Err(mpsc::error::SendError(job)) = sender.send(job).await {
+                    tracing::error!("Dispatch worker {index} is gone, running callback inline");
+                    job.await;
+                }
+            }
+        }
+    }
+
+    /// Runs `task` on its own task, regardless of the dispatch mode.
+    /// `drain()` waits for it like for dispatched jobs.
+    pub fn spawn(&self, task: Job) { self.tasks.spawn(task); }
+
+    /// Waits until every job that was handed to another task has finished.
+    /// Worker tasks are stopped once their queue is empty, and are spawned
+    /// again by the next `dispatch()`.
+    pub async fn drain(&self) {
+        self.workers.lock().unwrap_or_else(PoisonError::into_inner).take();
+        self.tasks.close();
+        self.tasks.wait().await;
+        self.tasks.reopen();
+    }
+
+    fn spawn_workers(&self, workers: usize, queue_capacity: usize) -> Vec<mpsc::Sender<Job>> {
+        (0..workers.max(1))
+            .map(|_| {
+                let (tx, mut rx) = mpsc::channel::<Job>(queue_capacity.max(1));
+                self.tasks.spawn(async move {
+                    while let Some(job) = rx.recv().await {
+                        job.await;
+                    }
+                });
+                tx
+            })
+            .collect()
+    }
+
+    fn worker_index(&self, event_type: EventType, workers: usize) -> usize {
+        // Truncating the hash is fine, it is only used to pick a worker
+        #[allow(clippy::cast_possible_truncation)]
+        let hash: usize = self.hasher.hash_one(event_type) as usize;
+        hash % workers
+    }
+}
@@ -1,28 +1,10 @@
 use super::super::{
-    Arc, BaseEventMessage, Client, Error, KeepaliveMessage, KeepalivePayload, NotificationMessage,
+    Arc, BaseEventMessage, Error, KeepaliveMessage, KeepalivePayload, NotificationMessage,
     NotificationPayload, ReconnectMessage, ReconnectPayload, Result, RevocationMessage,
-    RevocationPayload, RwLock, UserConfig, WelcomeMessage, WelcomePayload,
+    RevocationPayload, RwLock, WelcomeMessage, WelcomePayload,
 };
-use super::subscribe_to_chat;
 
-#[derive(Debug, Hash, PartialEq, Eq)]
-pub enum EventType {
-    ChatMessage,
-    Subscription,
-    Bits,
-}
-
-impl std::fmt::Display for EventType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Self::Bits => write!(f, "bits"),
-            Self::ChatMessage => write!(f, "chat_message"),
-            Self::Subscription => write!(f, "subscription"),
-        }
-    }
-}
-
-#[derive(Debug, Hash, PartialEq, Eq)]
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
 pub enum EventMessage {
     Welcome(WelcomeMessage),
     Keepalive(KeepaliveMessage),
@@ -39,14 +21,9 @@ pub enum EventMessage {
 ///
 /// - Returns `serde_json::Error` if parsing a `serde_json::Value` into a chosen
 ///   type fails, or if deserializing the event JSON fails for some reason
-/// - Returns `anyhow::Error` if session ID was not set during the welcome event
-/// - Returns `reqwest::Error` if subscribing to the event was unsuccessful
 pub async fn handle_event(
     raw: &str,
     session_id: Arc<RwLock<Option<String>>>,
-    http_client: Arc<Client>,
-    user_config: &UserConfig,
-    is_reconnect: bool,
 ) -> Result<EventMessage> {
     tracing::debug!("Handling event: {raw}");
     let peek: BaseEventMessage<serde_json::Value> = serde_json::from_str(raw)?;
@@ -61,18 +38,6 @@ pub async fn handle_event(
                 tracing::debug!("Got session_welcome.");
             }
 
-            if is_reconnect {
-                tracing::info!("Reconnect welcome received, skipping subscription");
-            } else {
-                let session_id: String = session_id
-                    .read()
-                    .await
-                    .clone()
-                    .ok_or_else(|| Error::NoneError("Tried to read None session ID".into()))?;
-
-                subscribe_to_chat(http_client, &session_id, user_config).await?;
-            }
-
             Ok(EventMessage::Welcome(msg))
         }
         "session_keepalive" => match serde_json::from_value::<KeepalivePayload>(peek.payload) {
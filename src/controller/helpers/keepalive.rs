@@ -0,0 +1,49 @@
+use std::time::Duration;
+
+use chrono::TimeDelta;
+
+use super::super::{DateTime, Utc};
+
+/// Liveness information about the current connection, updated by the
+/// controller on every frame it receives.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct KeepaliveStats {
+    /// When the last frame of any kind was received.
+    pub last_seen: Option<DateTime<Utc>>,
+
+    /// The keepalive window Twitch announced in `session_welcome`. If no
+    /// frame arrives within it (plus the configured grace period), the
+    /// connection is treated as dead.
+    pub keepalive_timeout: Option<Duration>,
+
+    /// How much later than the keepalive window the last
+    /// `session_keepalive` arrived. Negative if it arrived early.
+    pub last_jitter: Option<TimeDelta>,
+
+    /// The largest jitter measured on the current connection.
+    pub max_jitter: Option<TimeDelta>,
+}
+
+impl KeepaliveStats {
+    /// Marks a frame as received at `now`, returning the previous
+    /// `last_seen` timestamp.
+    pub(crate) const fn touch(&mut self, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        self.last_seen.replace(now)
+    }
+
+    /// Measures the jitter of a `session_keepalive` that arrived at `now`,
+    /// given the timestamp of the frame before it.
+    pub(crate) fn record_keepalive(&mut self, previous: Option<DateTime<Utc>>, now: DateTime<Utc>) {
+        let (Some(previous), Some(timeout)) = (previous, self.keepalive_timeout) else {
+            return;
+        };
+        let Ok(timeout) = TimeDelta::from_std(timeout) else {
+            return;
+        };
+
+        let jitter: TimeDelta = (now - previous) - timeout;
+        self.last_jitter = Some(jitter);
+        self.max_jitter = Some(self.max_jitter.map_or(jitter, |max| max.max(jitter)));
+        tracing::debug!("Keepalive jitter: {}ms", jitter.num_milliseconds());
+    }
+}
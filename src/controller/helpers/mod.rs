@@ -1,11 +1,23 @@
+mod backoff;
+mod callbacks;
+mod dedup;
+mod dispatch;
 mod event_handler;
-mod subscriber;
+mod keepalive;
+mod rejection;
+mod subscriptions;
 
-use std::sync::Arc;
+pub use backoff::{DisconnectReason, ReconnectAttempt, ReconnectPolicy};
+pub use callbacks::CallbackId;
+pub use dedup::{DedupCache, DedupConfig};
+pub use dispatch::{DispatchMode, Dispatcher};
+pub use event_handler::{EventMessage, handle_event};
+pub use keepalive::KeepaliveStats;
+pub use rejection::{RejectedMessage, RejectionReason};
+pub use subscriptions::{
+    ResubscribePolicy, SubscriptionError, SubscriptionFailurePolicy, SubscriptionOutcome,
+    SubscriptionVerdict, create_subscriptions, resolve_scopes,
+};
 
-pub use event_handler::{EventMessage, EventType, handle_event};
-use reqwest::Client as RClient;
-use serde_json::json;
-pub use subscriber::subscribe_to_chat;
-
-use crate::prelude::{Result, UserConfig};
+pub use crate::helix::delete_subscription;
+pub use crate::session::EventType;
@@ -0,0 +1,41 @@
+use std::time::Duration;
+
+use super::super::{DateTime, MessageId, Utc};
+
+/// Why the controller refused to dispatch a message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RejectionReason {
+    /// A message with the same ID was already dispatched.
+    Duplicate,
+    /// The message was older than the configured maximum age.
+    Stale {
+        /// How old the message was when it was received.
+        age: Duration,
+    },
+}
+
+impl std::fmt::Display for RejectionReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Duplicate => write!(f, "duplicate"),
+            Self::Stale { age } => write!(f, "stale ({}s old)", age.as_secs()),
+        }
+    }
+}
+
+/// A message the controller dropped instead of dispatching it, passed to
+/// the hook registered with `TwitchController::on_rejected_message()`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RejectedMessage {
+    /// The ID of the dropped message.
+    pub message_id: MessageId,
+
+    /// The type of the dropped message, e.g. `notification`.
+    pub message_type: String,
+
+    /// The UTC date and time Twitch sent the message.
+    pub message_timestamp: DateTime<Utc>,
+
+    /// Why the message was dropped.
+    pub reason: RejectionReason,
+}
@@ -0,0 +1,309 @@
+use super::super::{Arc, Client, Error, RwLock, UserConfig};
+use crate::helix::{
+    CreatedSubscription, HelixError, HelixErrorKind, MissingScopes, SubscriptionRequest, subscribe,
+    validate_token,
+};
+
+/// Decides whether a subscription that could not be created makes `start()`
+/// fail.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SubscriptionFailurePolicy {
+    /// Report the failure and keep running with the subscriptions that were
+    /// created.
+    Continue,
+
+    /// Fail on errors that won't go away without user action, a bad token
+    /// (401) or a missing scope (403, or found before sending the request).
+    /// Other refusals are only reported, a
+    /// request that could not be sent makes the controller reconnect and
+    /// try again.
+    #[default]
+    AbortOnAuthError,
+
+    /// Fail as soon as any subscription could not be created.
+    Abort,
+}
+
+impl SubscriptionFailurePolicy {
+    /// Whether `error` should make `start()` fail under this policy.
+    #[must_use]
+    pub const fn aborts_on(self, error: &SubscriptionError) -> bool {
+        match (self, error) {
+            (Self::Continue, _) => false,
+            (Self::AbortOnAuthError, SubscriptionError::Helix(e)) => {
+                matches!(e.kind(), HelixErrorKind::InvalidToken | HelixErrorKind::MissingScope)
+            }
+            (Self::AbortOnAuthError, SubscriptionError::MissingScopes(_)) => true,
+            (Self::AbortOnAuthError, SubscriptionError::Transport(_)) => false,
+            (Self::Abort, _) => true,
+        }
+    }
+
+    /// Decides what the outcomes of creating the subscription set mean for
+    /// the session: the first error this policy aborts on ends it, otherwise
+    /// a request that could not be sent makes it start over, unless the
+    /// policy is `Continue`.
+    #[must_use]
+    pub fn verdict(self, outcomes: &[SubscriptionOutcome]) -> SubscriptionVerdict {
+        let errors = outcomes.iter().filter_map(|outcome| outcome.result.as_ref().err());
+        let mut retry: Option<String> = None;
+
+        for error in errors {
+            if self.aborts_on(error) {
+                return SubscriptionVerdict::Abort(error.clone());
+            }
+            if retry.is_none()
+                && self != Self::Continue
+                && let SubscriptionError::Transport(message) = error
+            {
+                retry = Some(message.clone());
+            }
+        }
+
+        retry.map_or(SubscriptionVerdict::Keep, SubscriptionVerdict::Retry)
+    }
+}
+
+/// What the controller does after creating its subscription set, decided by
+/// `SubscriptionFailurePolicy::verdict()`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SubscriptionVerdict {
+    /// Keep the session running with the subscriptions that were created.
+    Keep,
+
+    /// Reconnect and create the subscription set again, a request could not
+    /// be sent.
+    Retry(String),
+
+    /// Make `start()` fail with this error.
+    Abort(SubscriptionError),
+}
+
+/// Decides whether the controller creates a subscription again after
+/// Twitch revoked it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ResubscribePolicy {
+    /// Only report revocations.
+    #[default]
+    Never,
+
+    /// Create the subscription again if the revocation reason is
+    /// recoverable. The token is refreshed first if a token refresher is
+    /// set, without one `authorization_revoked` is not retried.
+    Recoverable,
+}
+
+/// Why a subscription could not be created.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum SubscriptionError {
+    /// Twitch refused the subscription.
+    #[error(transparent)]
+    Helix(HelixError),
+
+    /// The token lacks scopes the subscription type requires, so the
+    /// request was never sent.
+    #[error(transparent)]
+    MissingScopes(MissingScopes),
+
+    /// The request could not be sent or its response could not be read.
+    #[error("Subscription request failed: {0}")]
+    Transport(String),
+}
+
+impl From<SubscriptionError> for Error {
+    fn from(value: SubscriptionError) -> Self {
+        match value {
+            SubscriptionError::Helix(e) => e.into(),
+            SubscriptionError::MissingScopes(e) => e.into(),
+            SubscriptionError::Transport(message) => Self::TransportError(message),
+        }
+    }
+}
+
+/// The result of creating one subscription of the controller's subscription
+/// set, passed to the callback registered with
+/// `TwitchController::on_subscription_result()`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SubscriptionOutcome {
+    /// The request that was sent, including the session ID.
+    pub request: SubscriptionRequest,
+
+    /// The created subscription along with its cost, or why it could not be
+    /// created.
+    pub result: std::result::Result<CreatedSubscription, SubscriptionError>,
+}
+
+/// Creates every subscription in `requests` on the WebSocket session
+/// `session_id`, one after another. A subscription that could not be
+/// created, whether Twitch refused it or the request failed, is reported in
+/// its outcome and doesn't stop the remaining ones from being created.
+pub async fn create_subscriptions(
+    client: Arc<Client>,
+    config: &UserConfig,
+    session_id: &str,
+    requests: &[SubscriptionRequest],
+) -> Vec<SubscriptionOutcome> {
+    let mut outcomes: Vec<SubscriptionOutcome> = Vec::with_capacity(requests.len());
+
+    for request in requests {
+        let request: SubscriptionRequest = request.clone().with_session_id(session_id);
+        let result = match subscribe(Arc::clone(&client), config, &request).await {
+            Ok(created) => {
+                tracing::info!(
+                    "Subscription {} costs {}, total cost is {}/{}",
+                    request.sub_type,
+                    created.subscription.cost,
+                    created.total_cost,
+                    created.max_total_cost
+                );
+                Ok(created)
+            }
+            Err(Error::HelixError(e)) => Err(SubscriptionError::Helix(*e)),
+            Err(Error::MissingScopes(e)) => Err(SubscriptionError::MissingScopes(e)),
+            Err(e) => Err(SubscriptionError::Transport(e.to_string())),
+        };
+
+        outcomes.push(SubscriptionOutcome { request, result });
+    }
+
+    outcomes
+}
+
+/// Returns a copy of `config`, asking Twitch for the scopes of the token
+/// first if they aren't known yet. The scopes are remembered, so
+/// subscriptions the token can't create fail without a request. If the
+/// token could not be validated, the scopes stay unknown and Helix decides.
+pub async fn resolve_scopes(client: Arc<Client>, config: &RwLock<UserConfig>) -> UserConfig {
+    let mut config = config.write().await;
+    if config.scopes.is_none() {
+        match validate_token(client, &config).await {
+            Ok(info) => config.scopes = Some(info.scopes),
+            Err(e) => tracing::warn!("Failed to validate the token, not checking scopes: {e}"),
+        }
+    }
+
+    config.clone()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        Arc, Client, SubscriptionError, SubscriptionFailurePolicy, SubscriptionOutcome,
+        SubscriptionVerdict, UserConfig, create_subscriptions,
+    };
+    use crate::helix::{HelixError, HelixRequest, SubscriptionRequest, TransportRequest};
+
+    // Nothing listens on the discard port, so every request fails to connect
+    fn refusing_client() -> Client {
+        let proxy: reqwest::Proxy = reqwest::Proxy::all("http://127.0.0.1:9").unwrap();
+        Client::builder().proxy(proxy).build().unwrap()
+    }
+
+    fn helix_error(status: u16) -> SubscriptionError {
+        SubscriptionError::Helix(HelixError {
+            status,
+            error: String::new(),
+            message: String::new(),
+            request: HelixRequest { method: "POST".into(), url: String::new(), body: None },
+        })
+    }
+
+    #[test]
+    fn auth_policy_aborts_on_auth_errors_only() {
+        let policy: SubscriptionFailurePolicy = SubscriptionFailurePolicy::AbortOnAuthError;
+        let transport: SubscriptionError = SubscriptionError::Transport("refused".into());
+
+        assert!(policy.aborts_on(&helix_error(401)));
+        assert!(policy.aborts_on(&helix_error(403)));
+        assert!(!policy.aborts_on(&helix_error(409)));
+        assert!(!policy.aborts_on(&transport));
+    }
+
+    #[tokio::test]
+    async fn records_missing_scopes_without_a_request() {
+        let config: UserConfig = UserConfig { scopes: Some(Vec::new()), ..Default::default() };
+        let requests: Vec<SubscriptionRequest> = vec![SubscriptionRequest::chat_message("1", "2")];
+
+        let outcomes: Vec<SubscriptionOutcome> =
+            create_subscriptions(Arc::new(refusing_client()), &config, "abc", &requests).await;
+
+        let Err(SubscriptionError::MissingScopes(missing)) = &outcomes[0].result else {
+            panic!("expected missing scopes, got {:?}", outcomes[0].result);
+        };
+        assert_eq!(missing.missing, [&["user:read:chat"]]);
+        let policy: SubscriptionFailurePolicy = SubscriptionFailurePolicy::AbortOnAuthError;
+        assert!(matches!(policy.verdict(&outcomes), SubscriptionVerdict::Abort(_)));
+    }
+
+    #[test]
+    fn transport_errors_follow_the_policy() {
+        let transport: SubscriptionError = SubscriptionError::Transport("refused".into());
+
+        assert!(!SubscriptionFailurePolicy::Continue.aborts_on(&transport));
+        assert!(SubscriptionFailurePolicy::Abort.aborts_on(&transport));
+    }
+
+    fn failed(error: SubscriptionError) -> SubscriptionOutcome {
+        let request: SubscriptionRequest = SubscriptionRequest::chat_message("1", "2");
+        SubscriptionOutcome { request, result: Err(error) }
+    }
+
+    #[test]
+    fn transport_errors_start_the_session_over() {
+        let outcomes: Vec<SubscriptionOutcome> = vec![
+            failed(helix_error(409)),
+            failed(SubscriptionError::Transport("refused".into())),
+        ];
+
+        assert_eq!(
+            SubscriptionFailurePolicy::AbortOnAuthError.verdict(&outcomes),
+            SubscriptionVerdict::Retry("refused".into())
+        );
+        assert_eq!(
+            SubscriptionFailurePolicy::Continue.verdict(&outcomes),
+            SubscriptionVerdict::Keep
+        );
+    }
+
+    #[test]
+    fn aborting_errors_win_over_retries() {
+        let outcomes: Vec<SubscriptionOutcome> = vec![
+            failed(SubscriptionError::Transport("refused".into())),
+            failed(helix_error(401)),
+        ];
+
+        assert_eq!(
+            SubscriptionFailurePolicy::AbortOnAuthError.verdict(&outcomes),
+            SubscriptionVerdict::Abort(helix_error(401))
+        );
+        assert_eq!(
+            SubscriptionFailurePolicy::Abort.verdict(&outcomes),
+            SubscriptionVerdict::Abort(SubscriptionError::Transport("refused".into()))
+        );
+    }
+
+    #[test]
+    fn created_subscriptions_keep_the_session() {
+        assert_eq!(SubscriptionFailurePolicy::Abort.verdict(&[]), SubscriptionVerdict::Keep);
+    }
+
+    #[tokio::test]
+    async fn records_transport_errors_as_outcomes() {
+        let requests: Vec<SubscriptionRequest> = vec![
+            SubscriptionRequest::chat_message("1", "2"),
+            SubscriptionRequest::chat_message("3", "4"),
+        ];
+
+        let config: UserConfig = UserConfig::default();
+        let outcomes: Vec<SubscriptionOutcome> =
+            create_subscriptions(Arc::new(refusing_client()), &config, "abc", &requests).await;
+
+        assert_eq!(outcomes.len(), 2);
+        for outcome in &outcomes {
+            let expected: TransportRequest =
+                TransportRequest::Websocket { session_id: "abc".into() };
+            assert_eq!(outcome.request.transport, expected);
+            assert!(matches!(outcome.result, Err(SubscriptionError::Transport(_))));
+        }
+    }
+}
@@ -1,19 +1,29 @@
 mod controller_core;
+mod handle;
 mod helpers;
 
 use std::collections::HashMap;
 use std::sync::Arc;
 
 pub use controller_core::TwitchController;
+pub use handle::ControllerHandle;
 use futures::FutureExt;
 use futures::future::BoxFuture;
-pub use helpers::{EventMessage, EventType};
+pub use helpers::{
+    CallbackId, DedupConfig, DisconnectReason, DispatchMode, EventMessage, EventType,
+    KeepaliveStats, ReconnectAttempt, ReconnectPolicy, RejectedMessage, RejectionReason,
+    ResubscribePolicy, SubscriptionError, SubscriptionFailurePolicy, SubscriptionOutcome,
+    SubscriptionVerdict,
+};
 use tokio::sync::RwLock;
 
 use crate::prelude::keepalive::{KeepaliveMessage, KeepalivePayload};
-use crate::prelude::notification::{NotificationMessage, NotificationPayload};
+use crate::prelude::notification::{NotificationMessage, NotificationMetadata, NotificationPayload};
 use crate::prelude::reconnect::{ReconnectMessage, ReconnectPayload};
-use crate::prelude::revocation::{RevocationMessage, RevocationPayload};
+use crate::prelude::revocation::{RevocationMessage, RevocationPayload, RevocationReason};
 use crate::prelude::welcome::{WelcomeMessage, WelcomePayload};
 use crate::prelude::*;
 pub use crate::session::ChatMessage;
+
+/// The Twitch EventSub WebSocket endpoint used for fresh connections.
+pub const EVENTSUB_WS_URL: &str = "wss://eventsub.wss.twitch.tv/ws";
@@ -24,6 +24,15 @@ pub enum Error {
     #[error("Dotenv init error: {0}")]
     DotenvError(#[from] dotenv::Error),
 
+    #[error("Helix API error: {0}")]
+    HelixError(#[from] Box<crate::helix::HelixError>),
+
+    #[error("Missing scopes: {0}")]
+    MissingScopes(#[from] crate::helix::MissingScopes),
+
+    #[error("Transport error: {0}")]
+    TransportError(String),
+
     #[error("None type extraction error: {0}")]
     NoneError(String),
 
@@ -0,0 +1,110 @@
+use super::{Deserialize, Error, Result};
+
+/// What kind of failure Twitch reported, derived from the HTTP status code.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum HelixErrorKind {
+    /// 401, the token is invalid, expired, or doesn't belong to the client ID.
+    InvalidToken,
+    /// 403, the token is missing a scope the request needs.
+    MissingScope,
+    /// 404, the requested resource doesn't exist.
+    NotFound,
+    /// 409, an identical subscription already exists.
+    DuplicateSubscription,
+    /// 429, the client exceeded its rate limit or subscription cost.
+    RateLimited,
+    /// Any other status code.
+    Other,
+}
+
+/// The request that Helix refused, kept for error reporting.
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct HelixRequest {
+    /// The HTTP method, e.g. `POST`.
+    pub method: String,
+    /// The full URL, including query parameters.
+    pub url: String,
+    /// The JSON body, if the request had one.
+    pub body: Option<serde_json::Value>,
+}
+
+/// A non-2xx response from the Helix API.
+#[derive(Debug, Clone, Hash, PartialEq, Eq, thiserror::Error)]
+#[error("{status} {error}: {message} ({} {})", request.method, request.url)]
+pub struct HelixError {
+    /// The HTTP status code of the response.
+    pub status: u16,
+    /// Twitch's `error` field, the reason phrase of the status code.
+    pub error: String,
+    /// Twitch's `message` field, describing what went wrong.
+    pub message: String,
+    /// The request that failed.
+    pub request: HelixRequest,
+}
+
+impl HelixError {
+    #[must_use]
+    pub const fn kind(&self) -> HelixErrorKind {
+        match self.status {
+            401 => HelixErrorKind::InvalidToken,
+            403 => HelixErrorKind::MissingScope,
+            404 => HelixErrorKind::NotFound,
+            409 => HelixErrorKind::DuplicateSubscription,
+            429 => HelixErrorKind::RateLimited,
+            _ => HelixErrorKind::Other,
+        }
+    }
+}
+
+/// A subscription the token lacks scopes for, refused before it was sent
+/// to Helix.
+#[derive(Debug, Clone, Hash, PartialEq, Eq, thiserror::Error)]
+#[error("{sub_type} needs one of each of the scope groups {missing:?}")]
+pub struct MissingScopes {
+    /// The subscription type, e.g. `channel.follow`.
+    pub sub_type: String,
+    /// The scope groups the token doesn't satisfy, see
+    /// [`EventType::required_scopes()`](crate::session::EventType::required_scopes).
+    pub missing: Vec<&'static [&'static str]>,
+}
+
+impl From<HelixError> for Error {
+    fn from(error: HelixError) -> Self { Self::HelixError(Box::new(error)) }
+}
+
+#[derive(Deserialize)]
+struct ErrorBody {
+    error: Option<String>,
+    message: Option<String>,
+}
+
+/// Turns a non-2xx response into an [`Error::HelixError`], passing
+/// successful responses through.
+///
+/// # Errors
+///
+/// - Returns `Error::HelixError` if the response status is not a success
+/// - Returns `reqwest::Error` if the error body could not be read
+pub async fn check_response(
+    response: reqwest::Response,
+    request: HelixRequest,
+) -> Result<reqwest::Response> {
+    let status: reqwest::StatusCode = response.status();
+    if status.is_success() {
+        return Ok(response);
+    }
+
+    let text: String = response.text().await?;
+    let body: Option<ErrorBody> = serde_json::from_str(&text).ok();
+    let (error, message) = body.map_or((None, None), |b| (b.error, b.message));
+
+    let error: HelixError = HelixError {
+        status: status.as_u16(),
+        error: error.unwrap_or_else(|| status.canonical_reason().unwrap_or_default().to_string()),
+        message: message.unwrap_or(text),
+        request,
+    };
+
+    tracing::error!("❌ Helix request failed: {error}");
+    Err(error.into())
+}
@@ -0,0 +1,21 @@
+mod error;
+mod subscriptions;
+mod token;
+
+use std::sync::Arc;
+
+pub use error::{HelixError, HelixErrorKind, HelixRequest, MissingScopes};
+pub use subscriptions::{
+    Condition, CreatedSubscription, SubscriptionFilter, SubscriptionPage, SubscriptionRequest,
+    TransportRequest, delete_subscription, delete_subscriptions, list_subscriptions,
+    list_subscriptions_page, subscribe, subscribe_to_chat,
+};
+pub use token::{TokenInfo, validate_token};
+
+use crate::prelude::{
+    Client, Deserialize, Error, EventType, Result, Subscription, SubscriptionStatus,
+    SubscriptionType, Url, UserConfig, Uuid,
+};
+
+/// Base URL of the Helix API.
+const HELIX_URL: &str = "https://api.twitch.tv/helix";
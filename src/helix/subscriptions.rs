@@ -0,0 +1,765 @@
+use serde::Serialize;
+
+use super::error::check_response;
+use super::{
+    Arc, Client, Deserialize, Error, EventType, HELIX_URL, HelixRequest, MissingScopes, Result,
+    Subscription, SubscriptionStatus, SubscriptionType, Url, UserConfig, Uuid,
+};
+
+/// The condition of an EventSub subscription, deciding which events it
+/// delivers. Each subscription type expects a specific shape, see the
+/// builders on [`SubscriptionRequest`].
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(untagged)]
+pub enum Condition {
+    /// Events of a single channel.
+    Broadcaster { broadcaster_user_id: String },
+
+    /// Chat events of a channel, read as `user_id`.
+    Chat { broadcaster_user_id: String, user_id: String },
+
+    /// Events of a channel that require a moderator to read them.
+    Moderator { broadcaster_user_id: String, moderator_user_id: String },
+
+    /// Channel Points events, optionally limited to a single reward.
+    Reward {
+        broadcaster_user_id: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        reward_id: Option<String>,
+    },
+
+    /// Raids into the given channel.
+    RaidTo { to_broadcaster_user_id: String },
+
+    /// Raids out of the given channel.
+    RaidFrom { from_broadcaster_user_id: String },
+
+    /// Any other condition, sent as is.
+    Custom(serde_json::Value),
+}
+
+/// How Twitch delivers the events of a subscription.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(tag = "method", rename_all = "snake_case")]
+pub enum TransportRequest {
+    /// Over the WebSocket session with the given ID.
+    Websocket { session_id: String },
+
+    /// To a webhook callback, signed with `secret`.
+    Webhook { callback: String, secret: String },
+}
+
+/// A request to create an EventSub subscription through Helix.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct SubscriptionRequest {
+    /// The subscription type, e.g. `channel.follow`.
+    #[serde(rename = "type")]
+    pub sub_type: String,
+
+    /// The version of the subscription type, e.g. `2`.
+    pub version: String,
+
+    /// Which events the subscription delivers.
+    pub condition: Condition,
+
+    /// How the events are delivered.
+    pub transport: TransportRequest,
+}
+
+impl SubscriptionRequest {
+    /// Creates a request for any subscription type. The transport is a
+    /// WebSocket without a session ID yet, set it with `with_session_id()`
+    /// or `with_transport()`.
+    #[must_use]
+    pub fn new(
+        sub_type: impl Into<String>,
+        version: impl Into<String>,
+        condition: Condition,
+    ) -> Self {
+        Self {
+            sub_type: sub_type.into(),
+            version: version.into(),
+            condition,
+            transport: TransportRequest::Websocket { session_id: String::new() },
+        }
+    }
+
+    /// Creates a request for the newest version of `event_type`.
+    #[must_use]
+    pub fn for_event(event_type: EventType, condition: Condition) -> Self {
+        Self::new(event_type.wire_name(), event_type.latest_version(), condition)
+    }
+
+    /// Delivers the events over the WebSocket session `session_id`.
+    #[must_use]
+    pub fn with_session_id(self, session_id: impl Into<String>) -> Self {
+        self.with_transport(TransportRequest::Websocket { session_id: session_id.into() })
+    }
+
+    /// Replaces how the events are delivered.
+    #[must_use]
+    pub fn with_transport(mut self, transport: TransportRequest) -> Self {
+        self.transport = transport;
+        self
+    }
+
+    /// Checks the scopes a token was `granted` against the ones the
+    /// subscription type requires. Types the crate doesn't know pass.
+    ///
+    /// # Errors
+    ///
+    /// - Returns `MissingScopes` with the scope groups `granted` doesn't
+    ///   satisfy
+    pub fn check_scopes(&self, granted: &[String]) -> std::result::Result<(), MissingScopes> {
+        let Some(event_type) = EventType::from_wire_name(&self.sub_type) else {
+            return Ok(());
+        };
+
+        let granted: Vec<&str> = granted.iter().map(String::as_str).collect();
+        let missing: Vec<&'static [&'static str]> = event_type.missing_scopes(&granted);
+        if missing.is_empty() {
+            return Ok(());
+        }
+
+        Err(MissingScopes { sub_type: self.sub_type.clone(), missing })
+    }
+
+    /// `channel.chat.message` v1, chat messages in `broadcaster_user_id`'s
+    /// channel, read as `user_id`.
+    #[must_use]
+    pub fn chat_message(
+        broadcaster_user_id: impl Into<String>,
+        user_id: impl Into<String>,
+    ) -> Self {
+        let condition: Condition = Condition::Chat {
+            broadcaster_user_id: broadcaster_user_id.into(),
+            user_id: user_id.into(),
+        };
+        Self::for_event(EventType::ChatMessage, condition)
+    }
+
+    /// `channel.follow` v2, new followers of `broadcaster_user_id`. The
+    /// token must belong to the broadcaster or one of their moderators.
+    #[must_use]
+    pub fn channel_follow(
+        broadcaster_user_id: impl Into<String>,
+        moderator_user_id: impl Into<String>,
+    ) -> Self {
+        let condition: Condition = Condition::Moderator {
+            broadcaster_user_id: broadcaster_user_id.into(),
+            moderator_user_id: moderator_user_id.into(),
+        };
+        Self::for_event(EventType::ChannelFollow, condition)
+    }
+
+    /// `channel.raid` v1, raids into `to_broadcaster_user_id`'s channel.
+    #[must_use]
+    pub fn channel_raid_to(to_broadcaster_user_id: impl Into<String>) -> Self {
+        let condition: Condition =
+            Condition::RaidTo { to_broadcaster_user_id: to_broadcaster_user_id.into() };
+        Self::for_event(EventType::ChannelRaid, condition)
+    }
+
+    /// `channel.raid` v1, raids out of `from_broadcaster_user_id`'s channel.
+    #[must_use]
+    pub fn channel_raid_from(from_broadcaster_user_id: impl Into<String>) -> Self {
+        let condition: Condition =
+            Condition::RaidFrom { from_broadcaster_user_id: from_broadcaster_user_id.into() };
+        Self::for_event(EventType::ChannelRaid, condition)
+    }
+
+    /// `channel.channel_points_custom_reward_redemption.add` v1, new
+    /// redemptions of any custom reward, or only of `reward_id`.
+    #[must_use]
+    pub fn reward_redemption_add(
+        broadcaster_user_id: impl Into<String>,
+        reward_id: Option<String>,
+    ) -> Self {
+        Self::for_reward(EventType::RewardRedemptionAdd, broadcaster_user_id, reward_id)
+    }
+
+    /// `channel.channel_points_custom_reward_redemption.update` v1, fulfilled
+    /// or canceled redemptions of any custom reward, or only of `reward_id`.
+    #[must_use]
+    pub fn reward_redemption_update(
+        broadcaster_user_id: impl Into<String>,
+        reward_id: Option<String>,
+    ) -> Self {
+        Self::for_reward(EventType::RewardRedemptionUpdate, broadcaster_user_id, reward_id)
+    }
+
+    /// `channel.subscribe` v1, new subscriptions to `broadcaster_user_id`.
+    #[must_use]
+    pub fn channel_subscribe(broadcaster_user_id: impl Into<String>) -> Self {
+        Self::for_broadcaster(EventType::Subscription, broadcaster_user_id)
+    }
+
+    /// `channel.subscription.end` v1, expired subscriptions.
+    #[must_use]
+    pub fn subscription_end(broadcaster_user_id: impl Into<String>) -> Self {
+        Self::for_broadcaster(EventType::SubscriptionEnd, broadcaster_user_id)
+    }
+
+    /// `channel.subscription.gift` v1, gifted subscriptions.
+    #[must_use]
+    pub fn subscription_gift(broadcaster_user_id: impl Into<String>) -> Self {
+        Self::for_broadcaster(EventType::SubscriptionGift, broadcaster_user_id)
+    }
+
+    /// `channel.subscription.message` v1, shared resubscriptions.
+    #[must_use]
+    pub fn subscription_message(broadcaster_user_id: impl Into<String>) -> Self {
+        Self::for_broadcaster(EventType::SubscriptionMessage, broadcaster_user_id)
+    }
+
+    /// `channel.cheer` v1, cheers in `broadcaster_user_id`'s channel.
+    #[must_use]
+    pub fn channel_cheer(broadcaster_user_id: impl Into<String>) -> Self {
+        Self::for_broadcaster(EventType::Bits, broadcaster_user_id)
+    }
+
+    /// `channel.bits.use` v1, bits used for cheers and Power-ups.
+    #[must_use]
+    pub fn bits_use(broadcaster_user_id: impl Into<String>) -> Self {
+        Self::for_broadcaster(EventType::BitsUse, broadcaster_user_id)
+    }
+
+    /// `channel.channel_points_custom_reward.add` v1, created custom rewards.
+    #[must_use]
+    pub fn custom_reward_add(broadcaster_user_id: impl Into<String>) -> Self {
+        Self::for_broadcaster(EventType::CustomRewardAdd, broadcaster_user_id)
+    }
+
+    /// `channel.channel_points_custom_reward.update` v1, changes to any custom
+    /// reward, or only to `reward_id`.
+    #[must_use]
+    pub fn custom_reward_update(
+        broadcaster_user_id: impl Into<String>,
+        reward_id: Option<String>,
+    ) -> Self {
+        Self::for_reward(EventType::CustomRewardUpdate, broadcaster_user_id, reward_id)
+    }
+
+    /// `channel.channel_points_custom_reward.remove` v1, deleted custom
+    /// rewards, any or only `reward_id`.
+    #[must_use]
+    pub fn custom_reward_remove(
+        broadcaster_user_id: impl Into<String>,
+        reward_id: Option<String>,
+    ) -> Self {
+        Self::for_reward(EventType::CustomRewardRemove, broadcaster_user_id, reward_id)
+    }
+
+    /// `channel.channel_points_automatic_reward_redemption.add` v2,
+    /// redemptions of automatic rewards.
+    #[must_use]
+    pub fn automatic_reward_redemption_add(broadcaster_user_id: impl Into<String>) -> Self {
+        Self::for_broadcaster(EventType::AutomaticRewardRedemptionAdd, broadcaster_user_id)
+    }
+
+    /// `channel.poll.begin` v1, started polls.
+    #[must_use]
+    pub fn poll_begin(broadcaster_user_id: impl Into<String>) -> Self {
+        Self::for_broadcaster(EventType::PollBegin, broadcaster_user_id)
+    }
+
+    /// `channel.poll.progress` v1, votes on polls.
+    #[must_use]
+    pub fn poll_progress(broadcaster_user_id: impl Into<String>) -> Self {
+        Self::for_broadcaster(EventType::PollProgress, broadcaster_user_id)
+    }
+
+    /// `channel.poll.end` v1, ended polls.
+    #[must_use]
+    pub fn poll_end(broadcaster_user_id: impl Into<String>) -> Self {
+        Self::for_broadcaster(EventType::PollEnd, broadcaster_user_id)
+    }
+
+    /// `channel.prediction.begin` v1, started predictions.
+    #[must_use]
+    pub fn prediction_begin(broadcaster_user_id: impl Into<String>) -> Self {
+        Self::for_broadcaster(EventType::PredictionBegin, broadcaster_user_id)
+    }
+
+    /// `channel.prediction.progress` v1, predictions made by users.
+    #[must_use]
+    pub fn prediction_progress(broadcaster_user_id: impl Into<String>) -> Self {
+        Self::for_broadcaster(EventType::PredictionProgress, broadcaster_user_id)
+    }
+
+    /// `channel.prediction.lock` v1, locked predictions.
+    #[must_use]
+    pub fn prediction_lock(broadcaster_user_id: impl Into<String>) -> Self {
+        Self::for_broadcaster(EventType::PredictionLock, broadcaster_user_id)
+    }
+
+    /// `channel.prediction.end` v1, resolved or canceled predictions.
+    #[must_use]
+    pub fn prediction_end(broadcaster_user_id: impl Into<String>) -> Self {
+        Self::for_broadcaster(EventType::PredictionEnd, broadcaster_user_id)
+    }
+
+    /// `channel.hype_train.begin` v2, started Hype Trains.
+    #[must_use]
+    pub fn hype_train_begin(broadcaster_user_id: impl Into<String>) -> Self {
+        Self::for_broadcaster(EventType::HypeTrainBegin, broadcaster_user_id)
+    }
+
+    /// `channel.hype_train.progress` v2, Hype Train contributions.
+    #[must_use]
+    pub fn hype_train_progress(broadcaster_user_id: impl Into<String>) -> Self {
+        Self::for_broadcaster(EventType::HypeTrainProgress, broadcaster_user_id)
+    }
+
+    /// `channel.hype_train.end` v2, ended Hype Trains.
+    #[must_use]
+    pub fn hype_train_end(broadcaster_user_id: impl Into<String>) -> Self {
+        Self::for_broadcaster(EventType::HypeTrainEnd, broadcaster_user_id)
+    }
+
+    /// `channel.goal.begin` v1, started creator goals.
+    #[must_use]
+    pub fn goal_begin(broadcaster_user_id: impl Into<String>) -> Self {
+        Self::for_broadcaster(EventType::GoalBegin, broadcaster_user_id)
+    }
+
+    /// `channel.goal.progress` v1, progress on creator goals.
+    #[must_use]
+    pub fn goal_progress(broadcaster_user_id: impl Into<String>) -> Self {
+        Self::for_broadcaster(EventType::GoalProgress, broadcaster_user_id)
+    }
+
+    /// `channel.goal.end` v1, ended creator goals.
+    #[must_use]
+    pub fn goal_end(broadcaster_user_id: impl Into<String>) -> Self {
+        Self::for_broadcaster(EventType::GoalEnd, broadcaster_user_id)
+    }
+
+    /// `channel.charity_campaign.donate` v1, donations to charity campaigns.
+    #[must_use]
+    pub fn charity_donation(broadcaster_user_id: impl Into<String>) -> Self {
+        Self::for_broadcaster(EventType::CharityDonation, broadcaster_user_id)
+    }
+
+    /// `channel.charity_campaign.start` v1, started charity campaigns.
+    #[must_use]
+    pub fn charity_campaign_start(broadcaster_user_id: impl Into<String>) -> Self {
+        Self::for_broadcaster(EventType::CharityCampaignStart, broadcaster_user_id)
+    }
+
+    /// `channel.charity_campaign.progress` v1, charity campaign totals.
+    #[must_use]
+    pub fn charity_campaign_progress(broadcaster_user_id: impl Into<String>) -> Self {
+        Self::for_broadcaster(EventType::CharityCampaignProgress, broadcaster_user_id)
+    }
+
+    /// `channel.charity_campaign.stop` v1, stopped charity campaigns.
+    #[must_use]
+    pub fn charity_campaign_stop(broadcaster_user_id: impl Into<String>) -> Self {
+        Self::for_broadcaster(EventType::CharityCampaignStop, broadcaster_user_id)
+    }
+
+    fn for_broadcaster(event_type: EventType, broadcaster_user_id: impl Into<String>) -> Self {
+        let condition: Condition =
+            Condition::Broadcaster { broadcaster_user_id: broadcaster_user_id.into() };
+        Self::for_event(event_type, condition)
+    }
+
+    fn for_reward(
+        event_type: EventType,
+        broadcaster_user_id: impl Into<String>,
+        reward_id: Option<String>,
+    ) -> Self {
+        let condition: Condition =
+            Condition::Reward { broadcaster_user_id: broadcaster_user_id.into(), reward_id };
+        Self::for_event(event_type, condition)
+    }
+}
+
+/// A subscription created through [`subscribe()`], along with the cost
+/// Twitch reported for it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CreatedSubscription {
+    /// The created subscription.
+    pub subscription: Subscription,
+
+    /// Total number of subscriptions of this client and user.
+    pub total: u32,
+
+    /// Sum of the costs of all subscriptions.
+    pub total_cost: u32,
+
+    /// Maximum total cost allowed for the subscriptions.
+    pub max_total_cost: u32,
+}
+
+#[derive(Deserialize)]
+struct CreateSubscriptionResponse {
+    data: Vec<Subscription>,
+    total: u32,
+    total_cost: u32,
+    max_total_cost: u32,
+}
+
+/// Narrows down which subscriptions [`list_subscriptions()`] returns.
+/// Helix accepts at most one filter per request.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum SubscriptionFilter {
+    /// Every subscription of the client.
+    #[default]
+    All,
+
+    /// Subscriptions with the given status, e.g.
+    /// [`SubscriptionStatus::WebsocketDisconnected`].
+    Status(SubscriptionStatus),
+
+    /// Subscriptions of the given type.
+    Type(SubscriptionType),
+
+    /// Subscriptions whose condition references the given user ID.
+    UserId(String),
+
+    /// The subscription with the given ID.
+    Id(Uuid),
+}
+
+impl SubscriptionFilter {
+    fn query(&self) -> Option<(&'static str, String)> {
+        match self {
+            Self::All => None,
+            Self::Status(status) => Some(("status", status.to_string())),
+            Self::Type(sub_type) => Some(("type", sub_type.to_string())),
+            Self::UserId(user_id) => Some(("user_id", user_id.clone())),
+            Self::Id(id) => Some(("subscription_id", id.to_string())),
+        }
+    }
+}
+
+/// One page of subscriptions returned by [`list_subscriptions_page()`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SubscriptionPage {
+    /// The subscriptions on this page.
+    pub subscriptions: Vec<Subscription>,
+
+    /// Total number of subscriptions matching the filter.
+    pub total: u32,
+
+    /// Sum of the costs of all subscriptions of this client and user.
+    pub total_cost: u32,
+
+    /// Maximum total cost allowed for the subscriptions.
+    pub max_total_cost: u32,
+
+    /// Cursor of the next page, `None` on the last page.
+    pub cursor: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct Pagination {
+    cursor: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct ListSubscriptionsResponse {
+    data: Vec<Subscription>,
+    total: u32,
+    total_cost: u32,
+    max_total_cost: u32,
+    #[serde(default)]
+    pagination: Option<Pagination>,
+}
+
+fn subscriptions_url() -> String { format!("{HELIX_URL}/eventsub/subscriptions") }
+
+/// This function creates an EventSub subscription from `request`. If the
+/// scopes of the token are known, they are checked first and a
+/// subscription the token can't create is never sent.
+///
+/// # Errors
+///
+/// - Returns `Error::MissingScopes` if `config.scopes` lacks a scope the
+///   subscription type requires
+/// - Returns `Error::HelixError` if Twitch refused to create the
+///   subscription, e.g. because of a bad token, a missing scope or a
+///   duplicate subscription
+/// - Returns `reqwest::Error` if there was an error while sending request to
+///   API endpoint, or if the response body could not be parsed
+/// - Returns `Error::NoneError` if the response contained no subscription
+pub async fn subscribe(
+    client: Arc<Client>,
+    config: &UserConfig,
+    request: &SubscriptionRequest,
+) -> Result<CreatedSubscription> {
+    if let Some(granted) = &config.scopes {
+        request.check_scopes(granted)?;
+    }
+
+    let url: String = subscriptions_url();
+    let response: reqwest::Response = client
+        .post(&url)
+        .header("Authorization", format!("Bearer {}", config.user_token))
+        .header("Client-Id", &config.client_id)
+        .header("Content-Type", "application/json")
+        .json(request)
+        .send()
+        .await?;
+
+    let failed: HelixRequest =
+        HelixRequest { method: "POST".into(), url, body: serde_json::to_value(request).ok() };
+    let response: reqwest::Response = check_response(response, failed).await?;
+
+    let sub_type: &str = &request.sub_type;
+    let created: CreateSubscriptionResponse = response.json().await?;
+    tracing::info!("✅ Subscribed to {sub_type}!");
+
+    let subscription: Subscription = created.data.into_iter().next().ok_or_else(|| {
+        Error::NoneError(format!("Helix returned no subscription for {sub_type}"))
+    })?;
+
+    Ok(CreatedSubscription {
+        subscription,
+        total: created.total,
+        total_cost: created.total_cost,
+        max_total_cost: created.max_total_cost,
+    })
+}
+
+/// This function handles subscribing to the `channel.chat.message`
+/// event from Twitch API endpoint
+///
+/// Returns the ID of the created subscription.
+///
+/// # Errors
+///
+/// - Returns `Error::HelixError` if Twitch refused to create the
+///   subscription
+/// - Returns `reqwest::Error` if if there was an error while sending request to
+///   API endpoint, or if the response body could not be parsed
+pub async fn subscribe_to_chat(
+    client: Arc<Client>,
+    session_id: &str,
+    config: &UserConfig,
+) -> Result<Uuid> {
+    let request: SubscriptionRequest =
+        SubscriptionRequest::chat_message(&config.broadcaster_id, &config.user_id)
+            .with_session_id(session_id);
+
+    let created: CreatedSubscription = subscribe(client, config, &request).await?;
+    Ok(created.subscription.id)
+}
+
+/// Deletes the EventSub subscription with the given ID.
+///
+/// # Errors
+///
+/// - Returns `Error::HelixError` if Twitch refused to delete it, e.g.
+///   because it no longer exists
+/// - Returns `reqwest::Error` if there was an error while sending request to
+///   API endpoint
+/// - Returns `url::ParseError` if the request URL could not be built
+pub async fn delete_subscription(
+    client: Arc<Client>,
+    subscription_id: Uuid,
+    config: &UserConfig,
+) -> Result<()> {
+    let url: Url =
+        Url::parse_with_params(&subscriptions_url(), [("id", subscription_id.to_string())])?;
+    let response: reqwest::Response = client
+        .delete(url.clone())
+        .header("Authorization", format!("Bearer {}", config.user_token))
+        .header("Client-Id", &config.client_id)
+        .send()
+        .await?;
+
+    let failed: HelixRequest =
+        HelixRequest { method: "DELETE".into(), url: url.into(), body: None };
+    check_response(response, failed).await?;
+
+    tracing::info!("🗑️ Deleted subscription {subscription_id}");
+    Ok(())
+}
+
+/// Fetches a single page of the EventSub subscriptions of the client
+/// matching `filter`. Pass the `cursor` of the previous page as `after` to
+/// fetch the next one.
+///
+/// # Errors
+///
+/// - Returns `Error::HelixError` if Twitch refused the request
+/// - Returns `reqwest::Error` if there was an error while sending request to
+///   API endpoint, or if the response body could not be parsed
+/// - Returns `url::ParseError` if the request URL could not be built
+pub async fn list_subscriptions_page(
+    client: Arc<Client>,
+    config: &UserConfig,
+    filter: &SubscriptionFilter,
+    after: Option<&str>,
+) -> Result<SubscriptionPage> {
+    let params: Vec<(&str, String)> = filter
+        .query()
+        .into_iter()
+        .chain(after.map(|cursor| ("after", cursor.to_string())))
+        .collect();
+
+    let url: Url = Url::parse_with_params(&subscriptions_url(), params)?;
+    let response: reqwest::Response = client
+        .get(url.clone())
+        .header("Authorization", format!("Bearer {}", config.user_token))
+        .header("Client-Id", &config.client_id)
+        .send()
+        .await?;
+
+    let failed: HelixRequest = HelixRequest { method: "GET".into(), url: url.into(), body: None };
+    let response: reqwest::Response = check_response(response, failed).await?;
+    let page: ListSubscriptionsResponse = response.json().await?;
+
+    Ok(SubscriptionPage {
+        subscriptions: page.data,
+        total: page.total,
+        total_cost: page.total_cost,
+        max_total_cost: page.max_total_cost,
+        cursor: page.pagination.and_then(|p| p.cursor).filter(|cursor| !cursor.is_empty()),
+    })
+}
+
+/// Fetches every EventSub subscription of the client matching `filter`,
+/// following the pagination cursor until the last page.
+///
+/// # Errors
+///
+/// - Returns `Error::HelixError` if Twitch refused one of the requests
+/// - Returns `reqwest::Error` if there was an error while sending request to
+///   API endpoint, or if the response body could not be parsed
+/// - Returns `url::ParseError` if the request URL could not be built
+pub async fn list_subscriptions(
+    client: Arc<Client>,
+    config: &UserConfig,
+    filter: &SubscriptionFilter,
+) -> Result<Vec<Subscription>> {
+    let mut subscriptions: Vec<Subscription> = Vec::new();
+    let mut cursor: Option<String> = None;
+
+    loop {
+        let page: SubscriptionPage =
+            list_subscriptions_page(Arc::clone(&client), config, filter, cursor.as_deref())
+                .await?;
+
+        subscriptions.extend(page.subscriptions);
+        cursor = page.cursor;
+        if cursor.is_none() {
+            return Ok(subscriptions);
+        }
+    }
+}
+
+/// Deletes every EventSub subscription of the client matching `filter`, for
+/// example the ones leaked by earlier sessions before starting a new one.
+/// Returns the number of deleted subscriptions.
+///
+/// # Errors
+///
+/// - Returns `Error::HelixError` if Twitch refused to list or delete one of
+///   the subscriptions, the remaining ones are not deleted
+/// - Returns `reqwest::Error` if there was an error while sending request to
+///   API endpoint
+/// - Returns `url::ParseError` if a request URL could not be built
+pub async fn delete_subscriptions(
+    client: Arc<Client>,
+    config: &UserConfig,
+    filter: &SubscriptionFilter,
+) -> Result<usize> {
+    let subscriptions: Vec<Subscription> =
+        list_subscriptions(Arc::clone(&client), config, filter).await?;
+
+    for subscription in &subscriptions {
+        delete_subscription(Arc::clone(&client), subscription.id, config).await?;
+    }
+
+    Ok(subscriptions.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{Value, json};
+
+    use super::{
+        Arc, Client, Condition, CreatedSubscription, Error, MissingScopes, Result,
+        SubscriptionRequest, UserConfig, subscribe,
+    };
+
+    fn body(request: &SubscriptionRequest) -> Value { serde_json::to_value(request).unwrap() }
+
+    #[test]
+    fn follow_uses_moderator_condition() {
+        let request: SubscriptionRequest =
+            SubscriptionRequest::channel_follow("1337", "9001").with_session_id("session");
+
+        assert_eq!(
+            body(&request),
+            json!({
+                "type": "channel.follow",
+                "version": "2",
+                "condition": { "broadcaster_user_id": "1337", "moderator_user_id": "9001" },
+                "transport": { "method": "websocket", "session_id": "session" }
+            })
+        );
+    }
+
+    #[test]
+    fn broadcaster_builders_use_latest_version() {
+        let request: SubscriptionRequest = SubscriptionRequest::hype_train_begin("1337");
+
+        assert_eq!(body(&request)["type"], "channel.hype_train.begin");
+        assert_eq!(body(&request)["version"], "2");
+        assert_eq!(body(&request)["condition"], json!({ "broadcaster_user_id": "1337" }));
+    }
+
+    #[test]
+    fn reward_builders_omit_missing_reward_id() {
+        let any: SubscriptionRequest = SubscriptionRequest::custom_reward_update("1337", None);
+        let one: SubscriptionRequest =
+            SubscriptionRequest::reward_redemption_update("1337", Some("abc".into()));
+
+        assert_eq!(body(&any)["condition"], json!({ "broadcaster_user_id": "1337" }));
+        assert_eq!(
+            body(&one)["condition"],
+            json!({ "broadcaster_user_id": "1337", "reward_id": "abc" })
+        );
+    }
+
+    #[test]
+    fn check_scopes_reports_missing_groups() {
+        let request: SubscriptionRequest = SubscriptionRequest::channel_follow("1337", "9001");
+        let granted: Vec<String> = vec!["user:read:chat".into()];
+
+        let missing: MissingScopes = request.check_scopes(&granted).unwrap_err();
+        assert_eq!(missing.sub_type, "channel.follow");
+        assert_eq!(missing.missing, [&["moderator:read:followers"]]);
+
+        let granted: Vec<String> = vec!["moderator:read:followers".into()];
+        assert!(request.check_scopes(&granted).is_ok());
+    }
+
+    #[test]
+    fn check_scopes_passes_unknown_types() {
+        let condition: Condition = Condition::Broadcaster { broadcaster_user_id: "1337".into() };
+        let request: SubscriptionRequest =
+            SubscriptionRequest::new("channel.unknown", "1", condition);
+
+        assert!(request.check_scopes(&[]).is_ok());
+    }
+
+    #[tokio::test]
+    async fn subscribe_refuses_missing_scopes_without_a_request() {
+        // Nothing listens on the discard port, so a sent request would fail
+        let proxy: reqwest::Proxy = reqwest::Proxy::all("http://127.0.0.1:9").unwrap();
+        let client: Client = Client::builder().proxy(proxy).build().unwrap();
+        let config: UserConfig = UserConfig { scopes: Some(Vec::new()), ..Default::default() };
+        let request: SubscriptionRequest =
+            SubscriptionRequest::chat_message("1337", "9001").with_session_id("session");
+
+        let result: Result<CreatedSubscription> =
+            subscribe(Arc::new(client), &config, &request).await;
+        assert!(matches!(result, Err(Error::MissingScopes(_))));
+    }
+}
@@ -0,0 +1,85 @@
+use super::error::check_response;
+use super::{Arc, Client, Deserialize, HelixRequest, Result, UserConfig};
+
+/// Endpoint reporting the owner and scopes of an access token.
+const VALIDATE_URL: &str = "https://id.twitch.tv/oauth2/validate";
+
+/// What Twitch reports about an access token.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct TokenInfo {
+    /// The client ID the token was issued to.
+    pub client_id: String,
+
+    /// Login of the user the token belongs to, `None` for app tokens.
+    #[serde(default)]
+    pub login: Option<String>,
+
+    /// ID of the user the token belongs to, `None` for app tokens.
+    #[serde(default)]
+    pub user_id: Option<String>,
+
+    /// The scopes granted to the token.
+    #[serde(default)]
+    pub scopes: Vec<String>,
+
+    /// Seconds until the token expires, `0` if it doesn't.
+    pub expires_in: u64,
+}
+
+/// Asks Twitch who the token of `config` belongs to and which scopes it
+/// was granted.
+///
+/// # Errors
+///
+/// - Returns `Error::HelixError` if Twitch rejected the token, e.g. because
+///   it expired
+/// - Returns `reqwest::Error` if there was an error while sending request to
+///   API endpoint, or if the response body could not be parsed
+pub async fn validate_token(client: Arc<Client>, config: &UserConfig) -> Result<TokenInfo> {
+    let response: reqwest::Response = client
+        .get(VALIDATE_URL)
+        .header("Authorization", format!("OAuth {}", config.user_token))
+        .send()
+        .await?;
+
+    let failed: HelixRequest =
+        HelixRequest { method: "GET".into(), url: VALIDATE_URL.into(), body: None };
+    let response: reqwest::Response = check_response(response, failed).await?;
+
+    Ok(response.json().await?)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::TokenInfo;
+
+    #[test]
+    fn parses_user_token() {
+        let info: TokenInfo = serde_json::from_value(json!({
+            "client_id": "wbmytr93xzw8zbg0p1izqyzzc5mbiz",
+            "login": "twitchdev",
+            "scopes": ["channel:read:subscriptions"],
+            "user_id": "141981764",
+            "expires_in": 5520838
+        }))
+        .unwrap();
+
+        assert_eq!(info.login.as_deref(), Some("twitchdev"));
+        assert_eq!(info.scopes, ["channel:read:subscriptions"]);
+    }
+
+    #[test]
+    fn parses_app_token() {
+        let info: TokenInfo = serde_json::from_value(json!({
+            "client_id": "wbmytr93xzw8zbg0p1izqyzzc5mbiz",
+            "scopes": [],
+            "expires_in": 5520838
+        }))
+        .unwrap();
+
+        assert!(info.user_id.is_none());
+        assert!(info.scopes.is_empty());
+    }
+}
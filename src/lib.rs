@@ -1,9 +1,11 @@
 pub mod controller;
 pub mod error;
+pub mod helix;
 pub mod prelude;
 pub mod session;
 pub mod utils;
 
+use controller::EVENTSUB_WS_URL;
 use prelude::{
     Client, ClientBuilder, Policy, Result, TwitchController, Url, UserConfig, connect_async,
 };
@@ -25,7 +27,7 @@ pub async fn create_twitch_controller() -> Result<TwitchController> {
         .install_default()
         .expect("Failed to initialize TLS");
 
-    let url: Url = Url::parse("wss://eventsub.wss.twitch.tv/ws")?;
+    let url: Url = Url::parse(EVENTSUB_WS_URL)?;
     let (ws_stream, _) = connect_async(url.to_string()).await?;
 
     let https_client: Client = ClientBuilder::new().redirect(Policy::none()).build()?;
@@ -16,11 +16,22 @@ pub use tokio_tungstenite::{MaybeTlsStream, WebSocketStream, connect_async};
 pub use url::Url;
 pub use uuid::Uuid;
 
-pub use crate::controller::{EventType, TwitchController};
+pub use crate::controller::{
+    ControllerHandle, DedupConfig, DispatchMode, ReconnectPolicy, ResubscribePolicy,
+    SubscriptionFailurePolicy, TwitchController,
+};
+pub use crate::helix::{
+    Condition, HelixError, SubscriptionFilter, SubscriptionRequest, TransportRequest,
+};
 pub use crate::session::{
-    BaseEventMessage, BaseMetadata, ChannelChatMessage, ChatMessage, MessageId, NotificationEvent,
-    keepalive_imports as keepalive, notification_imports as notification,
-    reconnect_imports as reconnect, revocation_imports as revocation, welcome_imports as welcome,
+    AutomaticRewardRedemption, BaseEventMessage, BaseMetadata, ChannelBitsUse, ChannelChatMessage,
+    ChannelCheer, ChannelFollow, ChannelRaid, ChannelSubscribe, ChannelSubscriptionEnd,
+    ChannelSubscriptionGift, ChannelSubscriptionMessage, CharityCampaign, CharityDonation,
+    ChatMessage, CustomReward, CustomRewardRedemption, EventPayload, EventType, Goal, HypeTrain,
+    MessageId, NotificationEvent, Poll, Prediction, Subscription, SubscriptionStatus,
+    SubscriptionType, SubscriptionVersion, keepalive_imports as keepalive,
+    notification_imports as notification, reconnect_imports as reconnect,
+    revocation_imports as revocation, welcome_imports as welcome,
 };
 pub use crate::utils::logging::setup_logger;
 pub use crate::utils::user_config::UserConfig;
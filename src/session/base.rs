@@ -1,12 +1,14 @@
-use super::{DateTime, Deserialize, Utc, Uuid};
+use super::{
+    DateTime, Deserialize, SubscriptionStatus, SubscriptionType, SubscriptionVersion, Utc, Uuid,
+};
 
-#[derive(Debug, Hash, PartialEq, Eq)]
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
 pub enum MessageId {
     StringId(String),
     UuidId(Uuid),
 }
 
-#[derive(Deserialize, Debug, Hash, PartialEq, Eq)]
+#[derive(Deserialize, Debug, Clone, Hash, PartialEq, Eq)]
 pub struct BaseMetadata {
     #[serde(deserialize_with = "super::deserialize_message_id")]
     pub message_id: MessageId,
@@ -22,25 +24,42 @@ pub struct BaseMetadata {
     pub subscription_version: Option<String>,
 }
 
-#[derive(Deserialize, Debug, Hash, PartialEq, Eq)]
+#[derive(Deserialize, Debug, Clone, Hash, PartialEq, Eq)]
 pub struct BaseEventMessage<T> {
     pub metadata: BaseMetadata,
     pub payload: T,
 }
 
-#[derive(Deserialize, Debug, Hash, PartialEq, Eq)]
+#[derive(Deserialize, Debug, Clone, Hash, PartialEq, Eq)]
 pub struct Transport {
     pub method: String,
-    pub session_id: String,
+
+    /// Set for the `websocket` method.
+    #[serde(default)]
+    pub session_id: Option<String>,
+
+    /// Set for the `webhook` method.
+    #[serde(default)]
+    pub callback: Option<String>,
+
+    /// When the websocket connected. Only reported by Helix when listing
+    /// websocket subscriptions.
+    #[serde(default)]
+    pub connected_at: Option<DateTime<Utc>>,
+
+    /// When the websocket disconnected. Only reported by Helix when listing
+    /// websocket subscriptions.
+    #[serde(default)]
+    pub disconnected_at: Option<DateTime<Utc>>,
 }
 
-#[derive(Deserialize, Debug, PartialEq, Eq, Hash)]
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Subscription {
     pub id: Uuid,
-    pub status: String,
+    pub status: SubscriptionStatus,
     #[serde(rename = "type")]
-    pub sub_type: String,
-    pub version: String,
+    pub sub_type: SubscriptionType,
+    pub version: SubscriptionVersion,
     pub cost: u32,
     pub condition: serde_json::Value,
     pub transport: Transport,
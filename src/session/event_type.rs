@@ -0,0 +1,417 @@
+/// Scope groups a token needs, see [`EventType::required_scopes()`].
+type Scopes = &'static [&'static [&'static str]];
+
+const NONE: Scopes = &[];
+const ADS: Scopes = &[&["channel:read:ads"]];
+const AUTOMOD_SETTINGS: Scopes = &[
+    &["moderator:read:automod_settings", "moderator:manage:automod_settings"],
+];
+const BITS: Scopes = &[&["bits:read"]];
+const CHANNEL_MODERATE: Scopes = &[
+    &["moderator:read:blocked_terms", "moderator:manage:blocked_terms"],
+    &["moderator:read:chat_settings", "moderator:manage:chat_settings"],
+    &["moderator:read:unban_requests", "moderator:manage:unban_requests"],
+    &["moderator:read:banned_users", "moderator:manage:banned_users"],
+    &["moderator:read:chat_messages", "moderator:manage:chat_messages"],
+    &["moderator:read:moderators"],
+    &["moderator:read:vips"],
+];
+const CHARITY: Scopes = &[&["channel:read:charity"]];
+const CHAT: Scopes = &[&["user:read:chat"]];
+const FOLLOWERS: Scopes = &[&["moderator:read:followers"]];
+const GOALS: Scopes = &[&["channel:read:goals"]];
+const GUEST_STAR: Scopes = &[
+    &[
+        "channel:read:guest_star",
+        "channel:manage:guest_star",
+        "moderator:read:guest_star",
+        "moderator:manage:guest_star",
+    ],
+];
+const HYPE_TRAIN: Scopes = &[&["channel:read:hype_train"]];
+const MANAGE_AUTOMOD: Scopes = &[&["moderator:manage:automod"]];
+const MODERATE: Scopes = &[&["channel:moderate"]];
+const MODERATION: Scopes = &[&["moderation:read"]];
+const POLLS: Scopes = &[&["channel:read:polls", "channel:manage:polls"]];
+const PREDICTIONS: Scopes = &[&["channel:read:predictions", "channel:manage:predictions"]];
+const REDEMPTIONS: Scopes = &[&["channel:read:redemptions", "channel:manage:redemptions"]];
+const SHIELD_MODE: Scopes = &[&["moderator:read:shield_mode", "moderator:manage:shield_mode"]];
+const SHOUTOUTS: Scopes = &[&["moderator:read:shoutouts", "moderator:manage:shoutouts"]];
+const SUBSCRIPTIONS: Scopes = &[&["channel:read:subscriptions"]];
+const SUSPICIOUS_USERS: Scopes = &[&["moderator:read:suspicious_users"]];
+const UNBAN_REQUESTS: Scopes = &[
+    &["moderator:read:unban_requests", "moderator:manage:unban_requests"],
+];
+const VIPS: Scopes = &[&["channel:read:vips", "channel:manage:vips"]];
+const WARNINGS: Scopes = &[&["moderator:read:warnings", "moderator:manage:warnings"]];
+const WHISPERS: Scopes = &[&["user:read:whispers", "user:manage:whispers"]];
+
+/// Every EventSub subscription type, the single source of truth for
+/// subscribing to, parsing and dispatching events.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum EventType {
+    /// `automod.message.hold`
+    AutomodMessageHold,
+    /// `automod.message.update`
+    AutomodMessageUpdate,
+    /// `automod.settings.update`
+    AutomodSettingsUpdate,
+    /// `automod.terms.update`
+    AutomodTermsUpdate,
+    /// `channel.bits.use`
+    BitsUse,
+    /// `channel.update`
+    ChannelUpdate,
+    /// `channel.follow`
+    ChannelFollow,
+    /// `channel.ad_break.begin`
+    AdBreakBegin,
+    /// `channel.chat.clear`
+    ChatClear,
+    /// `channel.chat.clear_user_messages`
+    ChatClearUserMessages,
+    /// `channel.chat.message`
+    ChatMessage,
+    /// `channel.chat.message_delete`
+    ChatMessageDelete,
+    /// `channel.chat.notification`
+    ChatNotification,
+    /// `channel.chat_settings.update`
+    ChatSettingsUpdate,
+    /// `channel.chat.user_message_hold`
+    ChatUserMessageHold,
+    /// `channel.chat.user_message_update`
+    ChatUserMessageUpdate,
+    /// `channel.shared_chat.begin`
+    SharedChatBegin,
+    /// `channel.shared_chat.update`
+    SharedChatUpdate,
+    /// `channel.shared_chat.end`
+    SharedChatEnd,
+    /// `channel.subscribe`
+    Subscription,
+    /// `channel.subscription.end`
+    SubscriptionEnd,
+    /// `channel.subscription.gift`
+    SubscriptionGift,
+    /// `channel.subscription.message`
+    SubscriptionMessage,
+    /// `channel.cheer`
+    Bits,
+    /// `channel.raid`
+    ChannelRaid,
+    /// `channel.ban`
+    ChannelBan,
+    /// `channel.unban`
+    ChannelUnban,
+    /// `channel.unban_request.create`
+    UnbanRequestCreate,
+    /// `channel.unban_request.resolve`
+    UnbanRequestResolve,
+    /// `channel.moderate`
+    ChannelModerate,
+    /// `channel.moderator.add`
+    ModeratorAdd,
+    /// `channel.moderator.remove`
+    ModeratorRemove,
+    /// `channel.guest_star_session.begin`
+    GuestStarSessionBegin,
+    /// `channel.guest_star_session.end`
+    GuestStarSessionEnd,
+    /// `channel.guest_star_guest.update`
+    GuestStarGuestUpdate,
+    /// `channel.guest_star_settings.update`
+    GuestStarSettingsUpdate,
+    /// `channel.channel_points_automatic_reward_redemption.add`
+    AutomaticRewardRedemptionAdd,
+    /// `channel.channel_points_custom_reward.add`
+    CustomRewardAdd,
+    /// `channel.channel_points_custom_reward.update`
+    CustomRewardUpdate,
+    /// `channel.channel_points_custom_reward.remove`
+    CustomRewardRemove,
+    /// `channel.channel_points_custom_reward_redemption.add`
+    RewardRedemptionAdd,
+    /// `channel.channel_points_custom_reward_redemption.update`
+    RewardRedemptionUpdate,
+    /// `channel.poll.begin`
+    PollBegin,
+    /// `channel.poll.progress`
+    PollProgress,
+    /// `channel.poll.end`
+    PollEnd,
+    /// `channel.prediction.begin`
+    PredictionBegin,
+    /// `channel.prediction.progress`
+    PredictionProgress,
+    /// `channel.prediction.lock`
+    PredictionLock,
+    /// `channel.prediction.end`
+    PredictionEnd,
+    /// `channel.suspicious_user.message`
+    SuspiciousUserMessage,
+    /// `channel.suspicious_user.update`
+    SuspiciousUserUpdate,
+    /// `channel.vip.add`
+    VipAdd,
+    /// `channel.vip.remove`
+    VipRemove,
+    /// `channel.warning.acknowledge`
+    WarningAcknowledge,
+    /// `channel.warning.send`
+    WarningSend,
+    /// `channel.charity_campaign.donate`
+    CharityDonation,
+    /// `channel.charity_campaign.start`
+    CharityCampaignStart,
+    /// `channel.charity_campaign.progress`
+    CharityCampaignProgress,
+    /// `channel.charity_campaign.stop`
+    CharityCampaignStop,
+    /// `conduit.shard.disabled`
+    ConduitShardDisabled,
+    /// `drop.entitlement.grant`
+    DropEntitlementGrant,
+    /// `extension.bits_transaction.create`
+    ExtensionBitsTransactionCreate,
+    /// `channel.goal.begin`
+    GoalBegin,
+    /// `channel.goal.progress`
+    GoalProgress,
+    /// `channel.goal.end`
+    GoalEnd,
+    /// `channel.hype_train.begin`
+    HypeTrainBegin,
+    /// `channel.hype_train.progress`
+    HypeTrainProgress,
+    /// `channel.hype_train.end`
+    HypeTrainEnd,
+    /// `channel.shield_mode.begin`
+    ShieldModeBegin,
+    /// `channel.shield_mode.end`
+    ShieldModeEnd,
+    /// `channel.shoutout.create`
+    ShoutoutCreate,
+    /// `channel.shoutout.receive`
+    ShoutoutReceive,
+    /// `stream.online`
+    StreamOnline,
+    /// `stream.offline`
+    StreamOffline,
+    /// `user.authorization.grant`
+    UserAuthorizationGrant,
+    /// `user.authorization.revoke`
+    UserAuthorizationRevoke,
+    /// `user.update`
+    UserUpdate,
+    /// `user.whisper.message`
+    WhisperReceived,
+}
+
+struct Spec {
+    wire_name: &'static str,
+    versions: &'static [&'static str],
+    scopes: Scopes,
+}
+
+impl EventType {
+    /// Every event type, in the order of the EventSub reference.
+    pub const ALL: &'static [Self] = &[
+        Self::AutomodMessageHold, Self::AutomodMessageUpdate, Self::AutomodSettingsUpdate,
+        Self::AutomodTermsUpdate, Self::BitsUse, Self::ChannelUpdate, Self::ChannelFollow,
+        Self::AdBreakBegin, Self::ChatClear, Self::ChatClearUserMessages, Self::ChatMessage,
+        Self::ChatMessageDelete, Self::ChatNotification, Self::ChatSettingsUpdate,
+        Self::ChatUserMessageHold, Self::ChatUserMessageUpdate, Self::SharedChatBegin,
+        Self::SharedChatUpdate, Self::SharedChatEnd, Self::Subscription, Self::SubscriptionEnd,
+        Self::SubscriptionGift, Self::SubscriptionMessage, Self::Bits, Self::ChannelRaid,
+        Self::ChannelBan, Self::ChannelUnban, Self::UnbanRequestCreate, Self::UnbanRequestResolve,
+        Self::ChannelModerate, Self::ModeratorAdd, Self::ModeratorRemove,
+        Self::GuestStarSessionBegin, Self::GuestStarSessionEnd, Self::GuestStarGuestUpdate,
+        Self::GuestStarSettingsUpdate, Self::AutomaticRewardRedemptionAdd, Self::CustomRewardAdd,
+        Self::CustomRewardUpdate, Self::CustomRewardRemove, Self::RewardRedemptionAdd,
+        Self::RewardRedemptionUpdate, Self::PollBegin, Self::PollProgress, Self::PollEnd,
+        Self::PredictionBegin, Self::PredictionProgress, Self::PredictionLock, Self::PredictionEnd,
+        Self::SuspiciousUserMessage, Self::SuspiciousUserUpdate, Self::VipAdd, Self::VipRemove,
+        Self::WarningAcknowledge, Self::WarningSend, Self::CharityDonation,
+        Self::CharityCampaignStart, Self::CharityCampaignProgress, Self::CharityCampaignStop,
+        Self::ConduitShardDisabled, Self::DropEntitlementGrant,
+        Self::ExtensionBitsTransactionCreate, Self::GoalBegin, Self::GoalProgress, Self::GoalEnd,
+        Self::HypeTrainBegin, Self::HypeTrainProgress, Self::HypeTrainEnd, Self::ShieldModeBegin,
+        Self::ShieldModeEnd, Self::ShoutoutCreate, Self::ShoutoutReceive, Self::StreamOnline,
+        Self::StreamOffline, Self::UserAuthorizationGrant, Self::UserAuthorizationRevoke,
+        Self::UserUpdate, Self::WhisperReceived,
+    ];
+
+    /// The subscription type of this event as Twitch sends it, e.g.
+    /// `channel.chat.message`.
+    #[must_use]
+    pub const fn wire_name(self) -> &'static str { self.spec().wire_name }
+
+    /// The versions of this subscription type Twitch supports, oldest first.
+    #[must_use]
+    pub const fn versions(self) -> &'static [&'static str] { self.spec().versions }
+
+    /// The newest version of this subscription type, used by the
+    /// [`SubscriptionRequest`](crate::helix::SubscriptionRequest) builders.
+    #[must_use]
+    pub const fn latest_version(self) -> &'static str {
+        let versions: &[&str] = self.versions();
+        versions[versions.len() - 1]
+    }
+
+    /// The OAuth scopes a user access token needs to subscribe to this
+    /// event. Every group is required, and any one scope of a group
+    /// satisfies it. Empty if no scope is needed.
+    #[must_use]
+    pub const fn required_scopes(self) -> &'static [&'static [&'static str]] {
+        self.spec().scopes
+    }
+
+    /// The scope groups of [`required_scopes()`](Self::required_scopes) not
+    /// satisfied by `granted`, empty if the token may subscribe.
+    #[must_use]
+    pub fn missing_scopes(self, granted: &[&str]) -> Vec<&'static [&'static str]> {
+        self.required_scopes()
+            .iter()
+            .filter(|group| !group.iter().any(|scope| granted.contains(scope)))
+            .copied()
+            .collect()
+    }
+
+    /// The event type whose callbacks also receive this event, grouping
+    /// related events. Callbacks registered for `Subscription` receive every
+    /// subscription event and callbacks registered for `Bits` every bits
+    /// event; other types only have their own callbacks.
+    #[must_use]
+    pub const fn family(self) -> Self {
+        match self {
+            Self::SubscriptionEnd | Self::SubscriptionGift | Self::SubscriptionMessage => {
+                Self::Subscription
+            }
+            Self::BitsUse => Self::Bits,
+            _ => self,
+        }
+    }
+
+    /// The event type of the subscription type `name`, `None` for types the
+    /// crate doesn't know.
+    #[must_use]
+    pub fn from_wire_name(name: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|event_type| event_type.wire_name() == name)
+    }
+
+    const fn spec(self) -> Spec {
+        let (wire_name, versions, scopes): (&str, &[&str], Scopes) = match self {
+            Self::AutomodMessageHold => ("automod.message.hold", &["1", "2"], MANAGE_AUTOMOD),
+            Self::AutomodMessageUpdate => ("automod.message.update", &["1", "2"], MANAGE_AUTOMOD),
+            Self::AutomodSettingsUpdate => ("automod.settings.update", &["1"], AUTOMOD_SETTINGS),
+            Self::AutomodTermsUpdate => ("automod.terms.update", &["1"], MANAGE_AUTOMOD),
+            Self::BitsUse => ("channel.bits.use", &["1"], BITS),
+            Self::ChannelUpdate => ("channel.update", &["2"], NONE),
+            Self::ChannelFollow => ("channel.follow", &["2"], FOLLOWERS),
+            Self::AdBreakBegin => ("channel.ad_break.begin", &["1"], ADS),
+            Self::ChatClear => ("channel.chat.clear", &["1"], CHAT),
+            Self::ChatClearUserMessages => ("channel.chat.clear_user_messages", &["1"], CHAT),
+            Self::ChatMessage => ("channel.chat.message", &["1"], CHAT),
+            Self::ChatMessageDelete => ("channel.chat.message_delete", &["1"], CHAT),
+            Self::ChatNotification => ("channel.chat.notification", &["1"], CHAT),
+            Self::ChatSettingsUpdate => ("channel.chat_settings.update", &["1"], CHAT),
+            Self::ChatUserMessageHold => ("channel.chat.user_message_hold", &["1"], CHAT),
+            Self::ChatUserMessageUpdate => ("channel.chat.user_message_update", &["1"], CHAT),
+            Self::SharedChatBegin => ("channel.shared_chat.begin", &["1"], NONE),
+            Self::SharedChatUpdate => ("channel.shared_chat.update", &["1"], NONE),
+            Self::SharedChatEnd => ("channel.shared_chat.end", &["1"], NONE),
+            Self::Subscription => ("channel.subscribe", &["1"], SUBSCRIPTIONS),
+            Self::SubscriptionEnd => ("channel.subscription.end", &["1"], SUBSCRIPTIONS),
+            Self::SubscriptionGift => ("channel.subscription.gift", &["1"], SUBSCRIPTIONS),
+            Self::SubscriptionMessage => ("channel.subscription.message", &["1"], SUBSCRIPTIONS),
+            Self::Bits => ("channel.cheer", &["1"], BITS),
+            Self::ChannelRaid => ("channel.raid", &["1"], NONE),
+            Self::ChannelBan => ("channel.ban", &["1"], MODERATE),
+            Self::ChannelUnban => ("channel.unban", &["1"], MODERATE),
+            Self::UnbanRequestCreate => ("channel.unban_request.create", &["1"], UNBAN_REQUESTS),
+            Self::UnbanRequestResolve => ("channel.unban_request.resolve", &["1"], UNBAN_REQUESTS),
+            Self::ChannelModerate => ("channel.moderate", &["1", "2"], CHANNEL_MODERATE),
+            Self::ModeratorAdd => ("channel.moderator.add", &["1"], MODERATION),
+            Self::ModeratorRemove => ("channel.moderator.remove", &["1"], MODERATION),
+            Self::GuestStarSessionBegin => {
+                ("channel.guest_star_session.begin", &["beta"], GUEST_STAR)
+            }
+            Self::GuestStarSessionEnd => ("channel.guest_star_session.end", &["beta"], GUEST_STAR),
+            Self::GuestStarGuestUpdate => {
+                ("channel.guest_star_guest.update", &["beta"], GUEST_STAR)
+            }
+            Self::GuestStarSettingsUpdate => {
+                ("channel.guest_star_settings.update", &["beta"], GUEST_STAR)
+            }
+            Self::AutomaticRewardRedemptionAdd => {
+                ("channel.channel_points_automatic_reward_redemption.add", &["1", "2"], REDEMPTIONS)
+            }
+            Self::CustomRewardAdd => {
+                ("channel.channel_points_custom_reward.add", &["1"], REDEMPTIONS)
+            }
+            Self::CustomRewardUpdate => {
+                ("channel.channel_points_custom_reward.update", &["1"], REDEMPTIONS)
+            }
+            Self::CustomRewardRemove => {
+                ("channel.channel_points_custom_reward.remove", &["1"], REDEMPTIONS)
+            }
+            Self::RewardRedemptionAdd => {
+                ("channel.channel_points_custom_reward_redemption.add", &["1"], REDEMPTIONS)
+            }
+            Self::RewardRedemptionUpdate => {
+                ("channel.channel_points_custom_reward_redemption.update", &["1"], REDEMPTIONS)
+            }
+            Self::PollBegin => ("channel.poll.begin", &["1"], POLLS),
+            Self::PollProgress => ("channel.poll.progress", &["1"], POLLS),
+            Self::PollEnd => ("channel.poll.end", &["1"], POLLS),
+            Self::PredictionBegin => ("channel.prediction.begin", &["1"], PREDICTIONS),
+            Self::PredictionProgress => ("channel.prediction.progress", &["1"], PREDICTIONS),
+            Self::PredictionLock => ("channel.prediction.lock", &["1"], PREDICTIONS),
+            Self::PredictionEnd => ("channel.prediction.end", &["1"], PREDICTIONS),
+            Self::SuspiciousUserMessage => {
+                ("channel.suspicious_user.message", &["1"], SUSPICIOUS_USERS)
+            }
+            Self::SuspiciousUserUpdate => {
+                ("channel.suspicious_user.update", &["1"], SUSPICIOUS_USERS)
+            }
+            Self::VipAdd => ("channel.vip.add", &["1"], VIPS),
+            Self::VipRemove => ("channel.vip.remove", &["1"], VIPS),
+            Self::WarningAcknowledge => ("channel.warning.acknowledge", &["1"], WARNINGS),
+            Self::WarningSend => ("channel.warning.send", &["1"], WARNINGS),
+            Self::CharityDonation => ("channel.charity_campaign.donate", &["1"], CHARITY),
+            Self::CharityCampaignStart => ("channel.charity_campaign.start", &["1"], CHARITY),
+            Self::CharityCampaignProgress => ("channel.charity_campaign.progress", &["1"], CHARITY),
+            Self::CharityCampaignStop => ("channel.charity_campaign.stop", &["1"], CHARITY),
+            Self::ConduitShardDisabled => ("conduit.shard.disabled", &["1"], NONE),
+            Self::DropEntitlementGrant => ("drop.entitlement.grant", &["1"], NONE),
+            Self::ExtensionBitsTransactionCreate => {
+                ("extension.bits_transaction.create", &["1"], NONE)
+            }
+            Self::GoalBegin => ("channel.goal.begin", &["1"], GOALS),
+            Self::GoalProgress => ("channel.goal.progress", &["1"], GOALS),
+            Self::GoalEnd => ("channel.goal.end", &["1"], GOALS),
+            Self::HypeTrainBegin => ("channel.hype_train.begin", &["1", "2"], HYPE_TRAIN),
+            Self::HypeTrainProgress => ("channel.hype_train.progress", &["1", "2"], HYPE_TRAIN),
+            Self::HypeTrainEnd => ("channel.hype_train.end", &["1", "2"], HYPE_TRAIN),
+            Self::ShieldModeBegin => ("channel.shield_mode.begin", &["1"], SHIELD_MODE),
+            Self::ShieldModeEnd => ("channel.shield_mode.end", &["1"], SHIELD_MODE),
+            Self::ShoutoutCreate => ("channel.shoutout.create", &["1"], SHOUTOUTS),
+            Self::ShoutoutReceive => ("channel.shoutout.receive", &["1"], SHOUTOUTS),
+            Self::StreamOnline => ("stream.online", &["1"], NONE),
+            Self::StreamOffline => ("stream.offline", &["1"], NONE),
+            Self::UserAuthorizationGrant => ("user.authorization.grant", &["1"], NONE),
+            Self::UserAuthorizationRevoke => ("user.authorization.revoke", &["1"], NONE),
+            Self::UserUpdate => ("user.update", &["1"], NONE),
+            Self::WhisperReceived => ("user.whisper.message", &["1"], WHISPERS),
+        };
+
+        Spec { wire_name, versions, scopes }
+    }
+}
+
+impl std::fmt::Display for EventType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.wire_name())
+    }
+}
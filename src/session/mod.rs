@@ -1,9 +1,11 @@
 mod base;
+mod event_type;
 mod keepalive;
 mod notification;
 mod notification_events;
 mod reconnect;
 mod revocation;
+mod subscription;
 mod welcome;
 
 use crate::prelude::{DateTime, Deserialize, Utc, Uuid, deserialize_message_id, from_rfc3339};
@@ -27,8 +29,21 @@ pub mod reconnect_imports {
 }
 
 pub mod revocation_imports {
-    pub use super::revocation::{RevocationMessage, RevocationPayload};
+    pub use super::revocation::{RevocationMessage, RevocationPayload, RevocationReason};
 }
 
 pub use base::{BaseEventMessage, BaseMetadata, MessageId, Subscription, Transport};
-pub use notification_events::{ChannelChatMessage, ChatMessage, NotificationEvent};
+pub use event_type::EventType;
+pub use notification_events::{
+    AutomaticReward, AutomaticRewardMessage, AutomaticRewardRedemption, AutomaticRewardType,
+    BitsType, ChannelBitsUse, ChannelChatMessage, ChannelCheer, ChannelFollow, ChannelRaid,
+    ChannelSubscribe, ChannelSubscriptionEnd, ChannelSubscriptionGift, ChannelSubscriptionMessage,
+    Charity, CharityCampaign, CharityDonation, ChatMessage, Cheermote, ContributionType,
+    CurrencyAmount, CustomReward, CustomRewardRedemption, Emote, EventPayload, Fragment, Goal,
+    GoalType, HypeTrain, HypeTrainContribution, HypeTrainType, NotificationEvent, OutcomeColor,
+    Poll, PollChoice, PollEndStatus, PowerUp, PowerUpEmote, PowerUpType, Prediction,
+    PredictionEndStatus, PredictionOutcome, RedeemedReward, RedemptionStatus, RewardCooldown,
+    RewardImage, RewardLimit, SharedTrainParticipant, SubscriptionEmote, SubscriptionMessageText,
+    SubscriptionTier, TopPredictor, VotingSettings,
+};
+pub use subscription::{SubscriptionStatus, SubscriptionType, SubscriptionVersion};
@@ -1,9 +1,6 @@
-use super::{
-    BaseMetadata, ChannelChatMessage, DateTime, Deserialize, MessageId, NotificationEvent,
-    Subscription, Utc,
-};
+use super::{BaseMetadata, DateTime, Deserialize, MessageId, NotificationEvent, Subscription, Utc};
 
-#[derive(Deserialize, Debug, Hash, PartialEq, Eq)]
+#[derive(Deserialize, Debug, Clone, Hash, PartialEq, Eq)]
 pub struct NotificationMetadata {
     #[serde(deserialize_with = "super::deserialize_message_id")]
     pub message_id: MessageId,
@@ -14,13 +11,13 @@ pub struct NotificationMetadata {
     pub subscription_version: String,
 }
 
-#[derive(Debug, Hash, PartialEq, Eq)]
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
 pub struct NotificationPayload {
     pub subscription: Subscription,
     pub event: NotificationEvent,
 }
 
-#[derive(Deserialize, Debug, Hash, PartialEq, Eq)]
+#[derive(Deserialize, Debug, Clone, Hash, PartialEq, Eq)]
 pub struct NotificationMessage {
     pub metadata: NotificationMetadata,
     pub payload: NotificationPayload,
@@ -57,14 +54,11 @@ impl<'de> Deserialize<'de> for NotificationPayload {
         }
 
         let raw: RawPayload = RawPayload::deserialize(deserializer)?;
-        let event: NotificationEvent = match raw.subscription.sub_type.as_str() {
-            "channel.chat.message" => {
-                let ccm: ChannelChatMessage =
-                    serde_json::from_value(raw.event).map_err(serde::de::Error::custom)?;
-                NotificationEvent::ChannelChatMessage(Box::new(ccm))
-            }
-            other => NotificationEvent::Other(serde_json::Value::String(other.to_string())),
-        };
+        let event: NotificationEvent = NotificationEvent::from_value(
+            &raw.subscription.sub_type,
+            raw.subscription.version.as_str(),
+            raw.event,
+        );
 
         Ok(Self { subscription: raw.subscription, event })
     }
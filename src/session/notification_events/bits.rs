@@ -0,0 +1,212 @@
+use super::{ChatMessage, Deserialize};
+
+/// A `channel.cheer` event, sent when a user cheers in the broadcaster's
+/// channel.
+#[derive(Deserialize, Debug, Clone, Hash, PartialEq, Eq)]
+pub struct ChannelCheer {
+    /// Whether the user cheered anonymously or not.
+    pub is_anonymous: bool,
+
+    /// The user ID for the user who cheered on the specified channel. `None`
+    /// if `is_anonymous` is true.
+    pub user_id: Option<String>,
+
+    /// The user login for the user who cheered on the specified channel.
+    /// `None` if `is_anonymous` is true.
+    pub user_login: Option<String>,
+
+    /// The user display name for the user who cheered on the specified
+    /// channel. `None` if `is_anonymous` is true.
+    pub user_name: Option<String>,
+
+    /// The requested broadcaster ID.
+    pub broadcaster_user_id: String,
+
+    /// The requested broadcaster login.
+    pub broadcaster_user_login: String,
+
+    /// The requested broadcaster display name.
+    pub broadcaster_user_name: String,
+
+    /// The message sent with the cheer.
+    pub message: String,
+
+    /// The number of bits cheered.
+    pub bits: u32,
+}
+
+/// How bits were used in a `channel.bits.use` event.
+#[derive(Deserialize, Debug, Clone, Copy, Hash, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum BitsType {
+    /// A cheer in chat.
+    Cheer,
+
+    /// A Power-up.
+    PowerUp,
+
+    /// A combo.
+    Combo,
+
+    /// A type this crate doesn't know yet.
+    #[serde(other)]
+    Unknown,
+}
+
+/// The kind of Power-up bits were used on.
+#[derive(Deserialize, Debug, Clone, Copy, Hash, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum PowerUpType {
+    MessageEffect,
+    Celebration,
+    GigantifyAnEmote,
+
+    /// A Power-up this crate doesn't know yet.
+    #[serde(other)]
+    Unknown,
+}
+
+/// The emote a Power-up was used with.
+#[derive(Deserialize, Debug, Clone, Hash, PartialEq, Eq)]
+pub struct PowerUpEmote {
+    /// The ID that uniquely identifies this emote.
+    pub id: String,
+
+    /// The human readable emote token.
+    pub name: String,
+}
+
+/// The Power-up bits were used on.
+#[derive(Deserialize, Debug, Clone, Hash, PartialEq, Eq)]
+pub struct PowerUp {
+    /// The kind of Power-up.
+    #[serde(rename = "type")]
+    pub power_up_type: PowerUpType,
+
+    /// Optional. The emote associated with the reward.
+    pub emote: Option<PowerUpEmote>,
+
+    /// Optional. The ID of the message effect.
+    pub message_effect_id: Option<String>,
+}
+
+/// A `channel.bits.use` event, sent whenever bits are used in the
+/// broadcaster's channel, for cheers as well as Power-ups.
+#[derive(Deserialize, Debug, Clone, Hash, PartialEq, Eq)]
+pub struct ChannelBitsUse {
+    /// The User ID of the channel where the bits were redeemed.
+    pub broadcaster_user_id: String,
+
+    /// The login of the channel where the bits were used.
+    pub broadcaster_user_login: String,
+
+    /// The display name of the channel where the bits were used.
+    pub broadcaster_user_name: String,
+
+    /// The User ID of the redeeming user.
+    pub user_id: String,
+
+    /// The login name of the redeeming user.
+    pub user_login: String,
+
+    /// The display name of the redeeming user.
+    pub user_name: String,
+
+    /// The number of bits used.
+    pub bits: u32,
+
+    /// How the bits were used.
+    #[serde(rename = "type")]
+    pub bits_type: BitsType,
+
+    /// Optional. The chat message in plain text and as fragments, including
+    /// cheermotes and emotes.
+    pub message: Option<ChatMessage>,
+
+    /// Optional. Data about the Power-up, set when `bits_type` is
+    /// `PowerUp`.
+    pub power_up: Option<PowerUp>,
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::{BitsType, ChannelBitsUse, ChannelCheer, PowerUpType};
+
+    #[test]
+    fn parses_anonymous_cheer() {
+        let cheer: ChannelCheer = serde_json::from_value(json!({
+            "is_anonymous": true,
+            "user_id": null,
+            "user_login": null,
+            "user_name": null,
+            "broadcaster_user_id": "1337",
+            "broadcaster_user_login": "cooler_user",
+            "broadcaster_user_name": "Cooler_User",
+            "message": "pogchamp",
+            "bits": 1000
+        }))
+        .unwrap();
+
+        assert!(cheer.is_anonymous);
+        assert_eq!(cheer.user_id, None);
+        assert_eq!(cheer.bits, 1000);
+    }
+
+    #[test]
+    fn parses_power_up() {
+        let bits: ChannelBitsUse = serde_json::from_value(json!({
+            "user_id": "141981764",
+            "user_login": "twitchdev",
+            "user_name": "TwitchDev",
+            "broadcaster_user_id": "141981764",
+            "broadcaster_user_login": "testbroadcaster",
+            "broadcaster_user_name": "TestBroadcaster",
+            "bits": 50,
+            "type": "power_up",
+            "power_up": {
+                "type": "gigantify_an_emote",
+                "emote": { "id": "emote_id", "name": "emote_name" },
+                "message_effect_id": null
+            },
+            "message": {
+                "text": "PogChamp",
+                "fragments": [
+                    {
+                        "type": "emote",
+                        "text": "PogChamp",
+                        "cheermote": null,
+                        "emote": { "id": "emote_id", "emote_set_id": "set_id" },
+                        "mention": null
+                    }
+                ]
+            }
+        }))
+        .unwrap();
+
+        assert_eq!(bits.bits_type, BitsType::PowerUp);
+        assert_eq!(bits.power_up.map(|p| p.power_up_type), Some(PowerUpType::GigantifyAnEmote));
+        assert_eq!(bits.message.map(|m| m.fragments.len()), Some(1));
+    }
+
+    #[test]
+    fn parses_cheer_without_power_up() {
+        let bits: ChannelBitsUse = serde_json::from_value(json!({
+            "user_id": "141981764",
+            "user_login": "twitchdev",
+            "user_name": "TwitchDev",
+            "broadcaster_user_id": "141981764",
+            "broadcaster_user_login": "testbroadcaster",
+            "broadcaster_user_name": "TestBroadcaster",
+            "bits": 2,
+            "type": "cheer",
+            "power_up": null,
+            "message": { "text": "cheer1 cheer1", "fragments": [] }
+        }))
+        .unwrap();
+
+        assert_eq!(bits.bits_type, BitsType::Cheer);
+        assert!(bits.power_up.is_none());
+    }
+}
@@ -1,6 +1,6 @@
 use super::{Deserialize, MessageId, MessageType};
 
-#[derive(Deserialize, Debug, Hash, PartialEq, Eq)]
+#[derive(Deserialize, Debug, Clone, Hash, PartialEq, Eq)]
 pub struct Badge {
     /// An ID that identifies this set of chat badges. For example, Bits or
     /// Subscriber.
@@ -17,7 +17,7 @@ pub struct Badge {
     pub info: String,
 }
 
-#[derive(Deserialize, Debug, Hash, PartialEq, Eq)]
+#[derive(Deserialize, Debug, Clone, Hash, PartialEq, Eq)]
 pub struct Cheermote {
     /// The full Cheermote string is the concatenation of {prefix} + {number of
     /// Bits}. For example, a full Cheermote string is Cheer100.
@@ -30,27 +30,32 @@ pub struct Cheermote {
     pub tier: u32,
 }
 
-#[derive(Deserialize, Debug, Hash, PartialEq, Eq)]
+#[derive(Deserialize, Debug, Clone, Hash, PartialEq, Eq)]
 pub struct Emote {
     /// An ID that uniquely identifies this emote.
     pub id: String,
 
-    /// An ID that identifies the emote set that the emote belongs to.
-    #[serde(rename = "emote_set_id")]
+    /// An ID that identifies the emote set that the emote belongs to. Empty
+    /// for emotes in resubscription messages.
+    #[serde(rename = "emote_set_id", default)]
     pub set_id: String,
 
-    /// The ID of the broadcaster who owns the emote.
+    /// The ID of the broadcaster who owns the emote. Empty for emotes in
+    /// resubscription messages.
+    #[serde(default)]
     pub owner_id: String,
 
     /// The formats that the emote is available in. This can be:
     ///   - animated - An animated GIF is available for this emote.
     ///   - static - A static PNG file is available for this emote.
     ///
-    /// Either one or both of them will be present in the `Vec<T>`
+    /// Either one or both of them will be present in the `Vec<T>`, none for
+    /// emotes in resubscription messages.
+    #[serde(default)]
     pub format: Vec<String>,
 }
 
-#[derive(Deserialize, Debug, Hash, PartialEq, Eq)]
+#[derive(Deserialize, Debug, Clone, Hash, PartialEq, Eq)]
 pub struct Mention {
     /// The user ID of the mentioned user.
     #[serde(rename = "user_id")]
@@ -65,7 +70,7 @@ pub struct Mention {
     pub login: String,
 }
 
-#[derive(Deserialize, Debug, Hash, PartialEq, Eq)]
+#[derive(Deserialize, Debug, Clone, Hash, PartialEq, Eq)]
 pub struct Fragment {
     #[serde(rename = "type")]
     /// Possible values: text, cheermote, emote, mention
@@ -84,7 +89,7 @@ pub struct Fragment {
     pub mention: Option<Mention>,
 }
 
-#[derive(Deserialize, Debug, Hash, PartialEq, Eq)]
+#[derive(Deserialize, Debug, Clone, Hash, PartialEq, Eq)]
 pub struct ChatMessage {
     /// The chat message in plain text.
     pub text: String,
@@ -93,7 +98,7 @@ pub struct ChatMessage {
     pub fragments: Vec<Fragment>,
 }
 
-#[derive(Deserialize, Debug, Hash, PartialEq, Eq)]
+#[derive(Deserialize, Debug, Clone, Hash, PartialEq, Eq)]
 pub struct Reply {
     /// An ID that uniquely identifies the parent message that this message
     /// is replying to.
@@ -124,7 +129,7 @@ pub struct Reply {
     pub thread_user_login: String,
 }
 
-#[derive(Deserialize, Debug, Hash, PartialEq, Eq)]
+#[derive(Deserialize, Debug, Clone, Hash, PartialEq, Eq)]
 pub struct ChannelChatMessage {
     /// The broadcaster user ID.
     pub broadcaster_user_id: String,
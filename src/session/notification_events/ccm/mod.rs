@@ -1,17 +1,9 @@
 mod ccm_impl;
 mod channel_chat_message;
 
-use channel_chat_message::ChannelChatMessage as CCM;
-
 use crate::prelude::{Deserialize, MessageId, deserialize_message_id};
 
-#[derive(Deserialize, Debug, Hash, PartialEq, Eq)]
-pub enum NotificationEvent {
-    ChannelChatMessage(Box<CCM>),
-    Other(serde_json::Value),
-}
-
-#[derive(Deserialize, Debug, Hash, PartialEq, Eq)]
+#[derive(Deserialize, Debug, Clone, Hash, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
 pub enum MessageType {
     Text,
@@ -22,4 +14,4 @@ pub enum MessageType {
     PowerUpsGigantifiedEmote,
 }
 
-pub use channel_chat_message::{ChannelChatMessage, ChatMessage};
+pub use channel_chat_message::{ChannelChatMessage, ChatMessage, Cheermote, Emote, Fragment};
@@ -0,0 +1,409 @@
+use super::{ChannelChatMessage, DateTime, Deserialize, Fragment, PowerUpEmote, Utc};
+
+/// The status of a custom reward redemption.
+#[derive(Deserialize, Debug, Clone, Copy, Hash, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum RedemptionStatus {
+    Unfulfilled,
+    Fulfilled,
+    Canceled,
+
+    /// Twitch reports `unknown`, or a status this crate doesn't know yet.
+    #[serde(other)]
+    Unknown,
+}
+
+/// Basic information about the custom reward that was redeemed.
+#[derive(Deserialize, Debug, Clone, Hash, PartialEq, Eq)]
+pub struct RedeemedReward {
+    /// The reward identifier.
+    pub id: String,
+
+    /// The reward name.
+    pub title: String,
+
+    /// The reward cost.
+    pub cost: u32,
+
+    /// The reward description.
+    pub prompt: String,
+}
+
+/// A `channel.channel_points_custom_reward_redemption.add` or `.update`
+/// event, sent when a viewer redeems a custom reward, or when the
+/// broadcaster fulfills or cancels the redemption.
+#[derive(Deserialize, Debug, Clone, Hash, PartialEq, Eq)]
+pub struct CustomRewardRedemption {
+    /// The redemption identifier.
+    pub id: String,
+
+    /// The requested broadcaster ID.
+    pub broadcaster_user_id: String,
+
+    /// The requested broadcaster login.
+    pub broadcaster_user_login: String,
+
+    /// The requested broadcaster display name.
+    pub broadcaster_user_name: String,
+
+    /// User ID of the user that redeemed the reward.
+    pub user_id: String,
+
+    /// Login of the user that redeemed the reward.
+    pub user_login: String,
+
+    /// Display name of the user that redeemed the reward.
+    pub user_name: String,
+
+    /// The user input provided. Empty string if not provided.
+    pub user_input: String,
+
+    /// The status of the redemption.
+    pub status: RedemptionStatus,
+
+    /// Basic information about the reward that was redeemed, at the time it
+    /// was redeemed.
+    pub reward: RedeemedReward,
+
+    /// RFC3339 timestamp of when the reward was redeemed.
+    #[serde(deserialize_with = "super::from_rfc3339")]
+    pub redeemed_at: DateTime<Utc>,
+}
+
+impl CustomRewardRedemption {
+    /// Whether `message` is the chat message sent with this redemption, for
+    /// rewards that require user input.
+    #[must_use]
+    pub fn is_for_message(&self, message: &ChannelChatMessage) -> bool {
+        message.channel_points_custom_reward_id.as_deref() == Some(self.reward.id.as_str())
+            && message.chatter_user_id == self.user_id
+            && message.message.text == self.user_input
+    }
+}
+
+/// Whether a limit of a custom reward is enabled, and its value.
+#[derive(Deserialize, Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub struct RewardLimit {
+    /// Whether the limit is enabled.
+    pub is_enabled: bool,
+
+    /// The maximum number of redemptions.
+    pub value: u32,
+}
+
+/// The cooldown of a custom reward.
+#[derive(Deserialize, Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub struct RewardCooldown {
+    /// Whether the cooldown is enabled.
+    pub is_enabled: bool,
+
+    /// The cooldown in seconds.
+    pub seconds: u32,
+}
+
+/// URLs of the image of a custom reward in three sizes.
+#[derive(Deserialize, Debug, Clone, Hash, PartialEq, Eq)]
+pub struct RewardImage {
+    pub url_1x: String,
+    pub url_2x: String,
+    pub url_4x: String,
+}
+
+/// A `channel.channel_points_custom_reward.add`, `.update` or `.remove`
+/// event, sent when the broadcaster creates, changes or deletes a custom
+/// reward.
+#[derive(Deserialize, Debug, Clone, Hash, PartialEq, Eq)]
+pub struct CustomReward {
+    /// The reward identifier.
+    pub id: String,
+
+    /// The requested broadcaster ID.
+    pub broadcaster_user_id: String,
+
+    /// The requested broadcaster login.
+    pub broadcaster_user_login: String,
+
+    /// The requested broadcaster display name.
+    pub broadcaster_user_name: String,
+
+    /// Is the reward currently enabled. If false, the reward won't show up to
+    /// viewers.
+    pub is_enabled: bool,
+
+    /// Is the reward currently paused. If true, viewers can't redeem.
+    pub is_paused: bool,
+
+    /// Is the reward currently in stock. If false, viewers can't redeem.
+    pub is_in_stock: bool,
+
+    /// The reward title.
+    pub title: String,
+
+    /// The reward cost.
+    pub cost: u32,
+
+    /// The reward description.
+    pub prompt: String,
+
+    /// Does the viewer need to enter information when redeeming the reward.
+    pub is_user_input_required: bool,
+
+    /// Should redemptions be set to fulfilled status immediately when
+    /// redeemed and skip the request queue instead of the normal unfulfilled
+    /// status.
+    pub should_redemptions_skip_request_queue: bool,
+
+    /// The maximum number of redemptions allowed per live stream.
+    pub max_per_stream: RewardLimit,
+
+    /// The maximum number of redemptions allowed per user per stream.
+    pub max_per_user_per_stream: RewardLimit,
+
+    /// Custom background color for the reward, in hex format.
+    pub background_color: String,
+
+    /// Set of custom images for the reward. `None` if no images have been
+    /// uploaded.
+    pub image: Option<RewardImage>,
+
+    /// Set of default images for the reward.
+    pub default_image: RewardImage,
+
+    /// Whether a cooldown is enabled and what the cooldown is in seconds.
+    pub global_cooldown: RewardCooldown,
+
+    /// Timestamp of the cooldown expiration. `None` if the reward isn't on
+    /// cooldown.
+    #[serde(default)]
+    pub cooldown_expires_at: Option<DateTime<Utc>>,
+
+    /// The number of redemptions redeemed during the current live stream.
+    /// `None` if the broadcaster's stream isn't live or `max_per_stream`
+    /// isn't enabled.
+    pub redemptions_redeemed_current_stream: Option<u32>,
+}
+
+/// The kind of an automatic reward.
+#[derive(Deserialize, Debug, Clone, Copy, Hash, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum AutomaticRewardType {
+    SingleMessageBypassSubMode,
+    SendHighlightedMessage,
+    RandomSubEmoteUnlock,
+    ChosenSubEmoteUnlock,
+    ChosenModifiedSubEmoteUnlock,
+    MessageEffect,
+    GigantifyAnEmote,
+    Celebration,
+
+    /// A reward this crate doesn't know yet.
+    #[serde(other)]
+    Unknown,
+}
+
+/// The automatic reward that was redeemed.
+#[derive(Deserialize, Debug, Clone, Hash, PartialEq, Eq)]
+pub struct AutomaticReward {
+    /// The kind of reward.
+    #[serde(rename = "type")]
+    pub reward_type: AutomaticRewardType,
+
+    /// Number of channel points used, `cost` in v1.
+    #[serde(alias = "cost")]
+    pub channel_points: u32,
+
+    /// Optional. The emote that was unlocked or modified, `unlocked_emote` in
+    /// v1.
+    #[serde(alias = "unlocked_emote")]
+    pub emote: Option<PowerUpEmote>,
+}
+
+/// The chat message sent with an automatic reward redemption.
+#[derive(Deserialize, Debug, Clone, Hash, PartialEq, Eq)]
+pub struct AutomaticRewardMessage {
+    /// The chat message in plain text.
+    pub text: String,
+
+    /// Ordered list of chat message fragments. Only sent by v2, empty for v1.
+    #[serde(default)]
+    pub fragments: Vec<Fragment>,
+}
+
+/// A `channel.channel_points_automatic_reward_redemption.add` event, sent
+/// when a viewer redeems an automatic reward. Both v1 and v2 are parsed.
+#[derive(Deserialize, Debug, Clone, Hash, PartialEq, Eq)]
+pub struct AutomaticRewardRedemption {
+    /// The ID of the redemption.
+    pub id: String,
+
+    /// The ID of the channel where the reward was redeemed.
+    pub broadcaster_user_id: String,
+
+    /// The login of the channel where the reward was redeemed.
+    pub broadcaster_user_login: String,
+
+    /// The display name of the channel where the reward was redeemed.
+    pub broadcaster_user_name: String,
+
+    /// The ID of the redeeming user.
+    pub user_id: String,
+
+    /// The login of the redeeming user.
+    pub user_login: String,
+
+    /// The display name of the redeeming user.
+    pub user_name: String,
+
+    /// An object that contains the reward information.
+    pub reward: AutomaticReward,
+
+    /// Optional. The message sent with the redemption.
+    pub message: Option<AutomaticRewardMessage>,
+
+    /// Optional. A message the user sent with the reward, only sent by v1.
+    #[serde(default)]
+    pub user_input: Option<String>,
+
+    /// The UTC date and time of when the reward was redeemed.
+    #[serde(deserialize_with = "super::from_rfc3339")]
+    pub redeemed_at: DateTime<Utc>,
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::{
+        AutomaticRewardRedemption, AutomaticRewardType, CustomReward, CustomRewardRedemption,
+        RedemptionStatus,
+    };
+
+    #[test]
+    fn parses_redemption() {
+        let redemption: CustomRewardRedemption = serde_json::from_value(json!({
+            "id": "17fa2df1-ad76-4804-bfa5-a40ef63efe63",
+            "broadcaster_user_id": "1337",
+            "broadcaster_user_login": "cool_user",
+            "broadcaster_user_name": "Cool_User",
+            "user_id": "9001",
+            "user_login": "cooler_user",
+            "user_name": "Cooler_User",
+            "user_input": "pogchamp",
+            "status": "unfulfilled",
+            "reward": {
+                "id": "92af127c-7326-4483-a52b-b0da0be61c01",
+                "title": "title",
+                "cost": 100,
+                "prompt": "reward prompt"
+            },
+            "redeemed_at": "2020-07-15T17:16:03.17106713Z"
+        }))
+        .unwrap();
+
+        assert_eq!(redemption.status, RedemptionStatus::Unfulfilled);
+        assert_eq!(redemption.reward.cost, 100);
+    }
+
+    #[test]
+    fn parses_reward() {
+        let reward: CustomReward = serde_json::from_value(json!({
+            "id": "9001",
+            "broadcaster_user_id": "1337",
+            "broadcaster_user_login": "cool_user",
+            "broadcaster_user_name": "Cool_User",
+            "is_enabled": true,
+            "is_paused": false,
+            "is_in_stock": true,
+            "title": "Cool Reward",
+            "cost": 100,
+            "prompt": "reward prompt",
+            "is_user_input_required": true,
+            "should_redemptions_skip_request_queue": false,
+            "cooldown_expires_at": null,
+            "redemptions_redeemed_current_stream": null,
+            "max_per_stream": { "is_enabled": true, "value": 1000 },
+            "max_per_user_per_stream": { "is_enabled": true, "value": 1000 },
+            "global_cooldown": { "is_enabled": true, "seconds": 1000 },
+            "background_color": "#FA1ED2",
+            "image": {
+                "url_1x": "https://static-cdn.jtvnw.net/image-1.png",
+                "url_2x": "https://static-cdn.jtvnw.net/image-2.png",
+                "url_4x": "https://static-cdn.jtvnw.net/image-4.png"
+            },
+            "default_image": {
+                "url_1x": "https://static-cdn.jtvnw.net/default-1.png",
+                "url_2x": "https://static-cdn.jtvnw.net/default-2.png",
+                "url_4x": "https://static-cdn.jtvnw.net/default-4.png"
+            }
+        }))
+        .unwrap();
+
+        assert_eq!(reward.max_per_stream.value, 1000);
+        assert!(reward.cooldown_expires_at.is_none());
+        assert!(reward.image.is_some());
+    }
+
+    #[test]
+    fn parses_automatic_redemption_v1() {
+        let redemption: AutomaticRewardRedemption = serde_json::from_value(json!({
+            "broadcaster_user_id": "12826",
+            "broadcaster_user_name": "Twitch",
+            "broadcaster_user_login": "twitch",
+            "user_id": "141981764",
+            "user_name": "TwitchDev",
+            "user_login": "twitchdev",
+            "id": "f024099a-e0aa-4339-8f50-ea2c4b4d7e8b",
+            "reward": {
+                "type": "send_highlighted_message",
+                "cost": 100,
+                "unlocked_emote": null
+            },
+            "message": {
+                "text": "Hello world! VoHiYo",
+                "emotes": [{ "id": "81274", "begin": 13, "end": 18 }]
+            },
+            "user_input": "Hello world! VoHiYo ",
+            "redeemed_at": "2024-02-23T21:14:34.260398045Z"
+        }))
+        .unwrap();
+
+        assert_eq!(redemption.reward.reward_type, AutomaticRewardType::SendHighlightedMessage);
+        assert_eq!(redemption.reward.channel_points, 100);
+        assert!(redemption.message.is_some_and(|m| m.fragments.is_empty()));
+    }
+
+    #[test]
+    fn parses_automatic_redemption_v2() {
+        let redemption: AutomaticRewardRedemption = serde_json::from_value(json!({
+            "broadcaster_user_id": "12826",
+            "broadcaster_user_name": "Twitch",
+            "broadcaster_user_login": "twitch",
+            "user_id": "141981764",
+            "user_name": "TwitchDev",
+            "user_login": "twitchdev",
+            "id": "f024099a-e0aa-4339-8f50-ea2c4b4d7e8b",
+            "reward": {
+                "type": "send_highlighted_message",
+                "channel_points": 100,
+                "emote": null
+            },
+            "message": {
+                "text": "Hello world! VoHiYo",
+                "fragments": [
+                    { "type": "text", "text": "Hello world! " },
+                    {
+                        "type": "emote",
+                        "text": "VoHiYo",
+                        "emote": { "id": "81274" }
+                    }
+                ]
+            },
+            "redeemed_at": "2024-08-12T21:14:34.260398045Z"
+        }))
+        .unwrap();
+
+        assert_eq!(redemption.reward.channel_points, 100);
+        assert_eq!(redemption.message.map(|m| m.fragments.len()), Some(2));
+        assert_eq!(redemption.user_input, None);
+    }
+}
@@ -0,0 +1,195 @@
+use super::{DateTime, Deserialize, Utc};
+
+/// An amount of money, as a value in the minor unit of its currency.
+#[derive(Deserialize, Debug, Clone, Hash, PartialEq, Eq)]
+pub struct CurrencyAmount {
+    /// The monetary amount. The amount is specified in the currency's minor
+    /// unit. For example, the minor units for USD is cents, so if the amount
+    /// is $5.50 USD, `value` is set to 550.
+    pub value: i64,
+
+    /// The number of decimal places used by the currency. For example, USD
+    /// uses two decimal places.
+    pub decimal_places: u32,
+
+    /// The ISO-4217 three-letter currency code that identifies the type of
+    /// currency in `value`.
+    pub currency: String,
+}
+
+impl CurrencyAmount {
+    /// The amount in the currency's major unit, e.g. `5.5` for $5.50 USD.
+    /// Only meant for display, as floating point math loses precision.
+    #[must_use]
+    pub fn as_f64(&self) -> f64 {
+        // Amounts are far below 2^52, so the conversion is exact
+        #[allow(clippy::cast_precision_loss)]
+        let value: f64 = self.value as f64;
+        value / 10_f64.powi(i32::try_from(self.decimal_places).unwrap_or(i32::MAX))
+    }
+}
+
+impl std::fmt::Display for CurrencyAmount {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let places: usize = usize::try_from(self.decimal_places).unwrap_or_default();
+        write!(f, "{:.places$} {}", self.as_f64(), self.currency)
+    }
+}
+
+/// The charity a campaign raises money for.
+#[derive(Deserialize, Debug, Clone, Hash, PartialEq, Eq)]
+pub struct Charity {
+    /// The charity's name.
+    #[serde(rename = "charity_name")]
+    pub name: String,
+
+    /// A description of the charity.
+    #[serde(rename = "charity_description")]
+    pub description: String,
+
+    /// A URL to an image of the charity's logo. The image's type is PNG and
+    /// its size is 100px X 100px.
+    #[serde(rename = "charity_logo")]
+    pub logo: String,
+
+    /// A URL to the charity's website.
+    #[serde(rename = "charity_website")]
+    pub website: String,
+}
+
+/// A `channel.charity_campaign.donate` event, sent when a user donates to
+/// the broadcaster's charity campaign.
+#[derive(Deserialize, Debug, Clone, Hash, PartialEq, Eq)]
+pub struct CharityDonation {
+    /// An ID that identifies the donation. The ID is unique across
+    /// campaigns.
+    pub id: String,
+
+    /// An ID that identifies the charity campaign.
+    pub campaign_id: String,
+
+    /// An ID that identifies the broadcaster that's running the campaign.
+    pub broadcaster_user_id: String,
+
+    /// The broadcaster's login name.
+    pub broadcaster_user_login: String,
+
+    /// The broadcaster's display name.
+    pub broadcaster_user_name: String,
+
+    /// An ID that identifies the user that donated to the campaign.
+    pub user_id: String,
+
+    /// The user's login name.
+    pub user_login: String,
+
+    /// The user's display name.
+    pub user_name: String,
+
+    /// The charity the campaign raises money for.
+    #[serde(flatten)]
+    pub charity: Charity,
+
+    /// The amount of money that the user donated.
+    pub amount: CurrencyAmount,
+}
+
+/// A `channel.charity_campaign.start`, `.progress` or `.stop` event, sent
+/// when the broadcaster starts a charity campaign, when its totals change,
+/// and when it ends.
+#[derive(Deserialize, Debug, Clone, Hash, PartialEq, Eq)]
+pub struct CharityCampaign {
+    /// An ID that identifies the charity campaign.
+    pub id: String,
+
+    /// An ID that identifies the broadcaster that's running the campaign.
+    pub broadcaster_id: String,
+
+    /// The broadcaster's login name.
+    pub broadcaster_login: String,
+
+    /// The broadcaster's display name.
+    pub broadcaster_name: String,
+
+    /// The charity the campaign raises money for.
+    #[serde(flatten)]
+    pub charity: Charity,
+
+    /// The current amount of donations that the campaign has received.
+    pub current_amount: CurrencyAmount,
+
+    /// The campaign's target fundraising goal.
+    pub target_amount: CurrencyAmount,
+
+    /// The UTC timestamp of when the broadcaster started the campaign, set
+    /// by start events.
+    #[serde(default)]
+    pub started_at: Option<DateTime<Utc>>,
+
+    /// The UTC timestamp of when the broadcaster stopped the campaign, set
+    /// by stop events.
+    #[serde(default)]
+    pub stopped_at: Option<DateTime<Utc>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::{CharityCampaign, CharityDonation, CurrencyAmount};
+
+    #[test]
+    fn formats_amount() {
+        let amount: CurrencyAmount =
+            CurrencyAmount { value: 550, decimal_places: 2, currency: String::from("USD") };
+
+        assert_eq!(amount.to_string(), "5.50 USD");
+        assert!((amount.as_f64() - 5.5).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn parses_donation() {
+        let donation: CharityDonation = serde_json::from_value(json!({
+            "id": "a1b2c3-aabb-4455-d1e2f3",
+            "campaign_id": "123-abc-456-def",
+            "broadcaster_user_id": "123456",
+            "broadcaster_user_name": "SunnySideUp",
+            "broadcaster_user_login": "sunnysideup",
+            "user_id": "654321",
+            "user_login": "generoususer1",
+            "user_name": "GenerousUser1",
+            "charity_name": "Example name",
+            "charity_description": "Example description",
+            "charity_logo": "https://abc.cloudfront.net/ppgf/1000/100.png",
+            "charity_website": "https://www.example.com",
+            "amount": { "value": 10000, "decimal_places": 2, "currency": "USD" }
+        }))
+        .unwrap();
+
+        assert_eq!(donation.charity.name, "Example name");
+        assert_eq!(donation.amount.value, 10000);
+        assert_eq!(donation.amount.to_string(), "100.00 USD");
+    }
+
+    #[test]
+    fn parses_campaign_stop() {
+        let campaign: CharityCampaign = serde_json::from_value(json!({
+            "id": "123-abc-456-def",
+            "broadcaster_id": "123456",
+            "broadcaster_name": "SunnySideUp",
+            "broadcaster_login": "sunnysideup",
+            "charity_name": "Example name",
+            "charity_description": "Example description",
+            "charity_logo": "https://abc.cloudfront.net/ppgf/1000/100.png",
+            "charity_website": "https://www.example.com",
+            "current_amount": { "value": 1450000, "decimal_places": 2, "currency": "USD" },
+            "target_amount": { "value": 1500000, "decimal_places": 2, "currency": "USD" },
+            "stopped_at": "2022-07-26T22:00:03.17106713Z"
+        }))
+        .unwrap();
+
+        assert_eq!(campaign.current_amount.to_string(), "14500.00 USD");
+        assert!(campaign.started_at.is_none());
+        assert!(campaign.stopped_at.is_some());
+    }
+}
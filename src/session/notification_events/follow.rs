@@ -0,0 +1,53 @@
+use super::{DateTime, Deserialize, Utc};
+
+/// A `channel.follow` v2 event, sent when a user follows the broadcaster.
+#[derive(Deserialize, Debug, Clone, Hash, PartialEq, Eq)]
+pub struct ChannelFollow {
+    /// The user ID for the user now following the specified channel.
+    pub user_id: String,
+
+    /// The user login for the user now following the specified channel.
+    pub user_login: String,
+
+    /// The user display name for the user now following the specified
+    /// channel.
+    pub user_name: String,
+
+    /// The requested broadcaster ID.
+    pub broadcaster_user_id: String,
+
+    /// The requested broadcaster login.
+    pub broadcaster_user_login: String,
+
+    /// The requested broadcaster display name.
+    pub broadcaster_user_name: String,
+
+    /// The UTC date and time when the follow occurred.
+    #[serde(deserialize_with = "super::from_rfc3339")]
+    pub followed_at: DateTime<Utc>,
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::ChannelFollow;
+
+    #[test]
+    fn parses_twitch_sample() {
+        let follow: ChannelFollow = serde_json::from_value(json!({
+            "user_id": "1234",
+            "user_login": "cool_user",
+            "user_name": "Cool_User",
+            "broadcaster_user_id": "1337",
+            "broadcaster_user_login": "cooler_user",
+            "broadcaster_user_name": "Cooler_User",
+            "followed_at": "2020-07-15T18:16:11.17106713Z"
+        }))
+        .unwrap();
+
+        assert_eq!(follow.user_login, "cool_user");
+        assert_eq!(follow.broadcaster_user_id, "1337");
+        assert_eq!(follow.followed_at.timestamp(), 1_594_836_971);
+    }
+}
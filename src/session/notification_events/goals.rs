@@ -0,0 +1,116 @@
+use super::{DateTime, Deserialize, Utc};
+
+/// What a creator goal counts.
+#[derive(Deserialize, Debug, Clone, Copy, Hash, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum GoalType {
+    /// The number of followers.
+    Follow,
+    /// The subscription points of all subscriptions.
+    Subscription,
+    /// The number of subscriptions.
+    SubscriptionCount,
+    /// The subscription points of new subscriptions.
+    NewSubscription,
+    /// The number of new subscriptions.
+    NewSubscriptionCount,
+    /// The number of bits cheered.
+    NewBit,
+    /// The number of unique cheerers.
+    NewCheerer,
+
+    /// A type this crate doesn't know yet.
+    #[serde(other)]
+    Unknown,
+}
+
+/// A `channel.goal.begin`, `.progress` or `.end` event, sent when a creator
+/// goal starts, progresses or ends.
+#[derive(Deserialize, Debug, Clone, Hash, PartialEq, Eq)]
+pub struct Goal {
+    /// An ID that identifies this event.
+    pub id: String,
+
+    /// An ID that uniquely identifies the broadcaster.
+    pub broadcaster_user_id: String,
+
+    /// The broadcaster's user handle.
+    pub broadcaster_user_login: String,
+
+    /// The broadcaster's display name.
+    pub broadcaster_user_name: String,
+
+    /// What the goal counts.
+    #[serde(rename = "type")]
+    pub goal_type: GoalType,
+
+    /// A description of the goal, if specified.
+    pub description: String,
+
+    /// Whether the broadcaster achieved their goal, set by end events.
+    pub is_achieved: Option<bool>,
+
+    /// The goal's current value.
+    pub current_amount: i64,
+
+    /// The goal's target value.
+    pub target_amount: i64,
+
+    /// The UTC timestamp of when the broadcaster created the goal.
+    #[serde(deserialize_with = "super::from_rfc3339")]
+    pub started_at: DateTime<Utc>,
+
+    /// The UTC timestamp of when the broadcaster ended the goal, set by end
+    /// events.
+    #[serde(default)]
+    pub ended_at: Option<DateTime<Utc>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::{Goal, GoalType};
+
+    #[test]
+    fn parses_end() {
+        let goal: Goal = serde_json::from_value(json!({
+            "id": "12345-abc-678-defgh",
+            "broadcaster_user_id": "141981764",
+            "broadcaster_user_name": "TwitchDev",
+            "broadcaster_user_login": "twitchdev",
+            "type": "subscription",
+            "description": "Help me get partner!",
+            "is_achieved": false,
+            "current_amount": 180,
+            "target_amount": 220,
+            "started_at": "2021-07-15T17:16:03.17106713Z",
+            "ended_at": "2020-07-16T17:16:03.17106713Z"
+        }))
+        .unwrap();
+
+        assert_eq!(goal.goal_type, GoalType::Subscription);
+        assert_eq!(goal.is_achieved, Some(false));
+        assert_eq!(goal.target_amount, 220);
+        assert!(goal.ended_at.is_some());
+    }
+
+    #[test]
+    fn unknown_goal_type() {
+        let goal: Goal = serde_json::from_value(json!({
+            "id": "12345-abc-678-defgh",
+            "broadcaster_user_id": "141981764",
+            "broadcaster_user_name": "TwitchDev",
+            "broadcaster_user_login": "twitchdev",
+            "type": "brand_new_goal",
+            "description": "",
+            "current_amount": 0,
+            "target_amount": 10,
+            "started_at": "2021-07-15T17:16:03.17106713Z"
+        }))
+        .unwrap();
+
+        assert_eq!(goal.goal_type, GoalType::Unknown);
+        assert_eq!(goal.is_achieved, None);
+    }
+}
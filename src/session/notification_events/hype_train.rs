@@ -0,0 +1,213 @@
+use super::{DateTime, Deserialize, Utc};
+
+/// How a user contributed to a Hype Train.
+#[derive(Deserialize, Debug, Clone, Copy, Hash, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ContributionType {
+    Bits,
+    Subscription,
+    Other,
+
+    /// A type this crate doesn't know yet.
+    #[serde(other)]
+    Unknown,
+}
+
+/// One of the top contributions to a Hype Train.
+#[derive(Deserialize, Debug, Clone, Hash, PartialEq, Eq)]
+pub struct HypeTrainContribution {
+    /// The ID of the user that made the contribution.
+    pub user_id: String,
+
+    /// The user's login name.
+    pub user_login: String,
+
+    /// The user's display name.
+    pub user_name: String,
+
+    /// The contribution method used.
+    #[serde(rename = "type")]
+    pub contribution_type: ContributionType,
+
+    /// The total amount contributed. If the type is bits, this is the number
+    /// of bits. If it is a subscription, this is 500, 1000 or 2500 for tier
+    /// 1, 2 or 3 subscriptions.
+    pub total: u64,
+}
+
+/// The kind of a Hype Train.
+#[derive(Deserialize, Debug, Clone, Copy, Hash, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum HypeTrainType {
+    Regular,
+    Treasure,
+    GoldenKappa,
+
+    /// A type this crate doesn't know yet.
+    #[serde(other)]
+    Unknown,
+}
+
+/// A broadcaster taking part in a shared Hype Train.
+#[derive(Deserialize, Debug, Clone, Hash, PartialEq, Eq)]
+pub struct SharedTrainParticipant {
+    /// The ID of the broadcaster.
+    pub broadcaster_user_id: String,
+
+    /// The login of the broadcaster.
+    pub broadcaster_user_login: String,
+
+    /// The display name of the broadcaster.
+    pub broadcaster_user_name: String,
+}
+
+/// A `channel.hype_train.begin`, `.progress` or `.end` v2 event, sent when a
+/// Hype Train starts, progresses or ends. v1 notifications are delivered as
+/// [`NotificationEvent::Other`](super::NotificationEvent::Other).
+#[derive(Deserialize, Debug, Clone, Hash, PartialEq, Eq)]
+pub struct HypeTrain {
+    /// The Hype Train ID.
+    pub id: String,
+
+    /// The requested broadcaster ID.
+    pub broadcaster_user_id: String,
+
+    /// The requested broadcaster login.
+    pub broadcaster_user_login: String,
+
+    /// The requested broadcaster display name.
+    pub broadcaster_user_name: String,
+
+    /// The kind of Hype Train.
+    #[serde(rename = "type")]
+    pub train_type: HypeTrainType,
+
+    /// The current level of the Hype Train.
+    pub level: u32,
+
+    /// Total points contributed to the Hype Train.
+    pub total: u64,
+
+    /// The number of points contributed to the Hype Train at the current
+    /// level, not set by end events.
+    pub progress: Option<u64>,
+
+    /// The number of points required to reach the next level, not set by
+    /// end events.
+    pub goal: Option<u64>,
+
+    /// The contributors with the most points contributed.
+    pub top_contributions: Vec<HypeTrainContribution>,
+
+    /// Whether the Hype Train is shared with other channels.
+    pub is_shared_train: bool,
+
+    /// The broadcasters participating in a shared Hype Train. `None` if it
+    /// isn't shared.
+    pub shared_train_participants: Option<Vec<SharedTrainParticipant>>,
+
+    /// The highest level the channel ever reached, not set by end events.
+    pub all_time_high_level: Option<u32>,
+
+    /// The most points the channel ever contributed, not set by end events.
+    pub all_time_high_total: Option<u64>,
+
+    /// The time when the Hype Train started.
+    #[serde(deserialize_with = "super::from_rfc3339")]
+    pub started_at: DateTime<Utc>,
+
+    /// The time when the Hype Train expires, not set by end events.
+    #[serde(default)]
+    pub expires_at: Option<DateTime<Utc>>,
+
+    /// The time when the Hype Train ended, set by end events.
+    #[serde(default)]
+    pub ended_at: Option<DateTime<Utc>>,
+
+    /// The time when the Hype Train cooldown ends, set by end events.
+    #[serde(default)]
+    pub cooldown_ends_at: Option<DateTime<Utc>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::{ContributionType, HypeTrain, HypeTrainType};
+
+    #[test]
+    fn parses_v2_begin() {
+        let train: HypeTrain = serde_json::from_value(json!({
+            "id": "1b0AsbInCHZW2SQFQkCzqN07Ib2",
+            "broadcaster_user_id": "1337",
+            "broadcaster_user_login": "cool_user",
+            "broadcaster_user_name": "Cool_User",
+            "total": 137,
+            "progress": 137,
+            "goal": 500,
+            "top_contributions": [
+                {
+                    "user_id": "123",
+                    "user_login": "pogchamp",
+                    "user_name": "PogChamp",
+                    "type": "bits",
+                    "total": 50
+                },
+                {
+                    "user_id": "456",
+                    "user_login": "kappa",
+                    "user_name": "Kappa",
+                    "type": "subscription",
+                    "total": 45
+                }
+            ],
+            "level": 2,
+            "all_time_high_level": 4,
+            "all_time_high_total": 2845,
+            "shared_train_participants": null,
+            "started_at": "2020-07-15T17:16:03.17106713Z",
+            "expires_at": "2020-07-15T17:16:11.17106713Z",
+            "type": "golden_kappa",
+            "is_shared_train": false
+        }))
+        .unwrap();
+
+        assert_eq!(train.train_type, HypeTrainType::GoldenKappa);
+        assert_eq!(train.level, 2);
+        assert_eq!(train.goal, Some(500));
+        assert_eq!(train.top_contributions[1].contribution_type, ContributionType::Subscription);
+        assert!(train.expires_at.is_some());
+        assert!(train.ended_at.is_none());
+    }
+
+    #[test]
+    fn parses_v2_end() {
+        let train: HypeTrain = serde_json::from_value(json!({
+            "id": "1b0AsbInCHZW2SQFQkCzqN07Ib2",
+            "broadcaster_user_id": "1337",
+            "broadcaster_user_login": "cool_user",
+            "broadcaster_user_name": "Cool_User",
+            "level": 2,
+            "total": 137,
+            "top_contributions": [],
+            "shared_train_participants": [
+                {
+                    "broadcaster_user_id": "456",
+                    "broadcaster_user_login": "pogchamp",
+                    "broadcaster_user_name": "PogChamp"
+                }
+            ],
+            "type": "treasure",
+            "is_shared_train": true,
+            "started_at": "2020-07-15T17:16:03.17106713Z",
+            "ended_at": "2020-07-15T17:16:11.17106713Z",
+            "cooldown_ends_at": "2020-07-15T18:16:11.17106713Z"
+        }))
+        .unwrap();
+
+        assert_eq!(train.train_type, HypeTrainType::Treasure);
+        assert_eq!(train.progress, None);
+        assert_eq!(train.shared_train_participants.map(|p| p.len()), Some(1));
+        assert!(train.cooldown_ends_at.is_some());
+    }
+}
@@ -1,3 +1,586 @@
+mod bits;
 mod ccm;
+mod channel_points;
+mod charity;
+mod follow;
+mod goals;
+mod hype_train;
+mod polls;
+mod raid;
+mod subscriptions;
 
-pub use ccm::{ChannelChatMessage, ChatMessage, NotificationEvent};
+pub use bits::{BitsType, ChannelBitsUse, ChannelCheer, PowerUp, PowerUpEmote, PowerUpType};
+pub use ccm::{ChannelChatMessage, ChatMessage, Cheermote, Emote, Fragment};
+pub use channel_points::{
+    AutomaticReward, AutomaticRewardMessage, AutomaticRewardRedemption, AutomaticRewardType,
+    CustomReward, CustomRewardRedemption, RedeemedReward, RedemptionStatus, RewardCooldown,
+    RewardImage, RewardLimit,
+};
+pub use charity::{Charity, CharityCampaign, CharityDonation, CurrencyAmount};
+pub use follow::ChannelFollow;
+pub use goals::{Goal, GoalType};
+pub use hype_train::{
+    ContributionType, HypeTrain, HypeTrainContribution, HypeTrainType, SharedTrainParticipant,
+};
+pub use polls::{
+    OutcomeColor, Poll, PollChoice, PollEndStatus, Prediction, PredictionEndStatus,
+    PredictionOutcome, TopPredictor, VotingSettings,
+};
+pub use raid::ChannelRaid;
+pub use subscriptions::{
+    ChannelSubscribe, ChannelSubscriptionEnd, ChannelSubscriptionGift, ChannelSubscriptionMessage,
+    SubscriptionEmote, SubscriptionMessageText, SubscriptionTier,
+};
+
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use super::{EventType, SubscriptionType};
+use crate::prelude::{DateTime, Deserialize, Utc, from_rfc3339};
+
+/// The parsed body of a notification, one variant per event the library
+/// understands.
+#[derive(Deserialize, Debug, Clone, Hash, PartialEq, Eq)]
+pub enum NotificationEvent {
+    ChannelChatMessage(Box<ChannelChatMessage>),
+    ChannelFollow(ChannelFollow),
+    ChannelSubscribe(ChannelSubscribe),
+    ChannelSubscriptionEnd(ChannelSubscriptionEnd),
+    ChannelSubscriptionGift(ChannelSubscriptionGift),
+    ChannelSubscriptionMessage(Box<ChannelSubscriptionMessage>),
+    ChannelCheer(ChannelCheer),
+    ChannelBitsUse(Box<ChannelBitsUse>),
+    ChannelRaid(ChannelRaid),
+    CustomRewardRedemptionAdd(Box<CustomRewardRedemption>),
+    CustomRewardRedemptionUpdate(Box<CustomRewardRedemption>),
+    CustomRewardAdd(Box<CustomReward>),
+    CustomRewardUpdate(Box<CustomReward>),
+    CustomRewardRemove(Box<CustomReward>),
+    AutomaticRewardRedemptionAdd(Box<AutomaticRewardRedemption>),
+    PollBegin(Box<Poll>),
+    PollProgress(Box<Poll>),
+    PollEnd(Box<Poll>),
+    PredictionBegin(Box<Prediction>),
+    PredictionProgress(Box<Prediction>),
+    PredictionLock(Box<Prediction>),
+    PredictionEnd(Box<Prediction>),
+    HypeTrainBegin(Box<HypeTrain>),
+    HypeTrainProgress(Box<HypeTrain>),
+    HypeTrainEnd(Box<HypeTrain>),
+    GoalBegin(Box<Goal>),
+    GoalProgress(Box<Goal>),
+    GoalEnd(Box<Goal>),
+    CharityDonation(Box<CharityDonation>),
+    CharityCampaignStart(Box<CharityCampaign>),
+    CharityCampaignProgress(Box<CharityCampaign>),
+    CharityCampaignStop(Box<CharityCampaign>),
+    /// The raw `event` object of a notification that wasn't parsed.
+    Other(Value),
+}
+
+/// Implemented by the typed payload of every notification event, tying it to
+/// its [`NotificationEvent`] variant and the [`EventType`] its callbacks are
+/// registered for.
+pub trait EventPayload: Clone + Send + Sync + 'static {
+    /// The event type callbacks for this payload are registered under.
+    const EVENT_TYPE: EventType;
+
+    /// Every event type delivering this payload, for payloads shared by
+    /// several events. Typed callbacks are registered under all of them.
+    const EVENT_TYPES: &'static [EventType] = &[Self::EVENT_TYPE];
+
+    /// The subscription versions this payload parses. Notifications of any
+    /// other version become [`NotificationEvent::Other`].
+    const VERSIONS: &'static [&'static str] = Self::EVENT_TYPE.versions();
+
+    /// Extracts this payload from `event`, or `None` if `event` holds a
+    /// different kind of payload.
+    fn from_event(event: &NotificationEvent) -> Option<&Self>;
+}
+
+impl EventPayload for ChannelChatMessage {
+    const EVENT_TYPE: EventType = EventType::ChatMessage;
+
+    fn from_event(event: &NotificationEvent) -> Option<&Self> {
+        match event {
+            NotificationEvent::ChannelChatMessage(ccm) => Some(ccm.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+impl EventPayload for ChannelFollow {
+    const EVENT_TYPE: EventType = EventType::ChannelFollow;
+
+    fn from_event(event: &NotificationEvent) -> Option<&Self> {
+        match event {
+            NotificationEvent::ChannelFollow(follow) => Some(follow),
+            _ => None,
+        }
+    }
+}
+
+impl EventPayload for ChannelSubscribe {
+    const EVENT_TYPE: EventType = EventType::Subscription;
+
+    fn from_event(event: &NotificationEvent) -> Option<&Self> {
+        match event {
+            NotificationEvent::ChannelSubscribe(sub) => Some(sub),
+            _ => None,
+        }
+    }
+}
+
+impl EventPayload for ChannelSubscriptionEnd {
+    const EVENT_TYPE: EventType = EventType::SubscriptionEnd;
+
+    fn from_event(event: &NotificationEvent) -> Option<&Self> {
+        match event {
+            NotificationEvent::ChannelSubscriptionEnd(end) => Some(end),
+            _ => None,
+        }
+    }
+}
+
+impl EventPayload for ChannelSubscriptionGift {
+    const EVENT_TYPE: EventType = EventType::SubscriptionGift;
+
+    fn from_event(event: &NotificationEvent) -> Option<&Self> {
+        match event {
+            NotificationEvent::ChannelSubscriptionGift(gift) => Some(gift),
+            _ => None,
+        }
+    }
+}
+
+impl EventPayload for ChannelSubscriptionMessage {
+    const EVENT_TYPE: EventType = EventType::SubscriptionMessage;
+
+    fn from_event(event: &NotificationEvent) -> Option<&Self> {
+        match event {
+            NotificationEvent::ChannelSubscriptionMessage(msg) => Some(msg.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+impl EventPayload for ChannelCheer {
+    const EVENT_TYPE: EventType = EventType::Bits;
+
+    fn from_event(event: &NotificationEvent) -> Option<&Self> {
+        match event {
+            NotificationEvent::ChannelCheer(cheer) => Some(cheer),
+            _ => None,
+        }
+    }
+}
+
+impl EventPayload for ChannelBitsUse {
+    const EVENT_TYPE: EventType = EventType::BitsUse;
+
+    fn from_event(event: &NotificationEvent) -> Option<&Self> {
+        match event {
+            NotificationEvent::ChannelBitsUse(bits) => Some(bits.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+impl EventPayload for ChannelRaid {
+    const EVENT_TYPE: EventType = EventType::ChannelRaid;
+
+    fn from_event(event: &NotificationEvent) -> Option<&Self> {
+        match event {
+            NotificationEvent::ChannelRaid(raid) => Some(raid),
+            _ => None,
+        }
+    }
+}
+
+impl EventPayload for CustomRewardRedemption {
+    const EVENT_TYPE: EventType = EventType::RewardRedemptionAdd;
+    const EVENT_TYPES: &'static [EventType] =
+        &[EventType::RewardRedemptionAdd, EventType::RewardRedemptionUpdate];
+
+    fn from_event(event: &NotificationEvent) -> Option<&Self> {
+        match event {
+            NotificationEvent::CustomRewardRedemptionAdd(redemption)
+            | NotificationEvent::CustomRewardRedemptionUpdate(redemption) => {
+                Some(redemption.as_ref())
+            }
+            _ => None,
+        }
+    }
+}
+
+impl EventPayload for CustomReward {
+    const EVENT_TYPE: EventType = EventType::CustomRewardAdd;
+    const EVENT_TYPES: &'static [EventType] =
+        &[EventType::CustomRewardAdd, EventType::CustomRewardUpdate, EventType::CustomRewardRemove];
+
+    fn from_event(event: &NotificationEvent) -> Option<&Self> {
+        match event {
+            NotificationEvent::CustomRewardAdd(reward)
+            | NotificationEvent::CustomRewardUpdate(reward)
+            | NotificationEvent::CustomRewardRemove(reward) => Some(reward.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+impl EventPayload for AutomaticRewardRedemption {
+    const EVENT_TYPE: EventType = EventType::AutomaticRewardRedemptionAdd;
+
+    fn from_event(event: &NotificationEvent) -> Option<&Self> {
+        match event {
+            NotificationEvent::AutomaticRewardRedemptionAdd(redemption) => {
+                Some(redemption.as_ref())
+            }
+            _ => None,
+        }
+    }
+}
+
+impl EventPayload for Poll {
+    const EVENT_TYPE: EventType = EventType::PollBegin;
+    const EVENT_TYPES: &'static [EventType] =
+        &[EventType::PollBegin, EventType::PollProgress, EventType::PollEnd];
+
+    fn from_event(event: &NotificationEvent) -> Option<&Self> {
+        match event {
+            NotificationEvent::PollBegin(poll)
+            | NotificationEvent::PollProgress(poll)
+            | NotificationEvent::PollEnd(poll) => Some(poll.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+impl EventPayload for Prediction {
+    const EVENT_TYPE: EventType = EventType::PredictionBegin;
+    const EVENT_TYPES: &'static [EventType] = &[
+        EventType::PredictionBegin,
+        EventType::PredictionProgress,
+        EventType::PredictionLock,
+        EventType::PredictionEnd,
+    ];
+
+    fn from_event(event: &NotificationEvent) -> Option<&Self> {
+        match event {
+            NotificationEvent::PredictionBegin(prediction)
+            | NotificationEvent::PredictionProgress(prediction)
+            | NotificationEvent::PredictionLock(prediction)
+            | NotificationEvent::PredictionEnd(prediction) => Some(prediction.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+impl EventPayload for HypeTrain {
+    const EVENT_TYPE: EventType = EventType::HypeTrainBegin;
+    const EVENT_TYPES: &'static [EventType] =
+        &[EventType::HypeTrainBegin, EventType::HypeTrainProgress, EventType::HypeTrainEnd];
+    // v1 lacks the train type and shared train fields, it stays `Other`
+    const VERSIONS: &'static [&'static str] = &["2"];
+
+    fn from_event(event: &NotificationEvent) -> Option<&Self> {
+        match event {
+            NotificationEvent::HypeTrainBegin(train)
+            | NotificationEvent::HypeTrainProgress(train)
+            | NotificationEvent::HypeTrainEnd(train) => Some(train.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+impl EventPayload for Goal {
+    const EVENT_TYPE: EventType = EventType::GoalBegin;
+    const EVENT_TYPES: &'static [EventType] =
+        &[EventType::GoalBegin, EventType::GoalProgress, EventType::GoalEnd];
+
+    fn from_event(event: &NotificationEvent) -> Option<&Self> {
+        match event {
+            NotificationEvent::GoalBegin(goal)
+            | NotificationEvent::GoalProgress(goal)
+            | NotificationEvent::GoalEnd(goal) => Some(goal.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+impl EventPayload for CharityDonation {
+    const EVENT_TYPE: EventType = EventType::CharityDonation;
+
+    fn from_event(event: &NotificationEvent) -> Option<&Self> {
+        match event {
+            NotificationEvent::CharityDonation(donation) => Some(donation.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+impl EventPayload for CharityCampaign {
+    const EVENT_TYPE: EventType = EventType::CharityCampaignStart;
+    const EVENT_TYPES: &'static [EventType] = &[
+        EventType::CharityCampaignStart,
+        EventType::CharityCampaignProgress,
+        EventType::CharityCampaignStop,
+    ];
+
+    fn from_event(event: &NotificationEvent) -> Option<&Self> {
+        match event {
+            NotificationEvent::CharityCampaignStart(campaign)
+            | NotificationEvent::CharityCampaignProgress(campaign)
+            | NotificationEvent::CharityCampaignStop(campaign) => Some(campaign.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+impl NotificationEvent {
+    /// Parses the `event` object of a notification of type `sub_type` and
+    /// version `version`. Events the library doesn't parse, versions their
+    /// payload wasn't written for and bodies that fail to parse become
+    /// [`NotificationEvent::Other`] holding the raw `event`, so one bad body
+    /// never ends the session.
+    #[must_use]
+    pub fn from_value(sub_type: &SubscriptionType, version: &str, event: Value) -> Self {
+        let parsed: Option<serde_json::Result<Self>> = match sub_type.event_type() {
+            Some(EventType::ChatMessage) => {
+                parse(version, &event, |ccm| Self::ChannelChatMessage(Box::new(ccm)))
+            }
+            Some(EventType::ChannelFollow) => parse(version, &event, Self::ChannelFollow),
+            Some(EventType::Subscription) => parse(version, &event, Self::ChannelSubscribe),
+            Some(EventType::SubscriptionEnd) => {
+                parse(version, &event, Self::ChannelSubscriptionEnd)
+            }
+            Some(EventType::SubscriptionGift) => {
+                parse(version, &event, Self::ChannelSubscriptionGift)
+            }
+            Some(EventType::SubscriptionMessage) => {
+                parse(version, &event, |msg| Self::ChannelSubscriptionMessage(Box::new(msg)))
+            }
+            Some(EventType::Bits) => parse(version, &event, Self::ChannelCheer),
+            Some(EventType::BitsUse) => {
+                parse(version, &event, |bits| Self::ChannelBitsUse(Box::new(bits)))
+            }
+            Some(EventType::ChannelRaid) => parse(version, &event, Self::ChannelRaid),
+            Some(EventType::RewardRedemptionAdd) => {
+                parse(version, &event, |r| Self::CustomRewardRedemptionAdd(Box::new(r)))
+            }
+            Some(EventType::RewardRedemptionUpdate) => {
+                parse(version, &event, |r| Self::CustomRewardRedemptionUpdate(Box::new(r)))
+            }
+            Some(EventType::CustomRewardAdd) => {
+                parse(version, &event, |reward| Self::CustomRewardAdd(Box::new(reward)))
+            }
+            Some(EventType::CustomRewardUpdate) => {
+                parse(version, &event, |reward| Self::CustomRewardUpdate(Box::new(reward)))
+            }
+            Some(EventType::CustomRewardRemove) => {
+                parse(version, &event, |reward| Self::CustomRewardRemove(Box::new(reward)))
+            }
+            Some(EventType::AutomaticRewardRedemptionAdd) => {
+                parse(version, &event, |r| Self::AutomaticRewardRedemptionAdd(Box::new(r)))
+            }
+            Some(EventType::PollBegin) => {
+                parse(version, &event, |poll| Self::PollBegin(Box::new(poll)))
+            }
+            Some(EventType::PollProgress) => {
+                parse(version, &event, |poll| Self::PollProgress(Box::new(poll)))
+            }
+            Some(EventType::PollEnd) => {
+                parse(version, &event, |poll| Self::PollEnd(Box::new(poll)))
+            }
+            Some(EventType::PredictionBegin) => {
+                parse(version, &event, |p| Self::PredictionBegin(Box::new(p)))
+            }
+            Some(EventType::PredictionProgress) => {
+                parse(version, &event, |p| Self::PredictionProgress(Box::new(p)))
+            }
+            Some(EventType::PredictionLock) => {
+                parse(version, &event, |p| Self::PredictionLock(Box::new(p)))
+            }
+            Some(EventType::PredictionEnd) => {
+                parse(version, &event, |p| Self::PredictionEnd(Box::new(p)))
+            }
+            Some(EventType::HypeTrainBegin) => {
+                parse(version, &event, |train| Self::HypeTrainBegin(Box::new(train)))
+            }
+            Some(EventType::HypeTrainProgress) => {
+                parse(version, &event, |train| Self::HypeTrainProgress(Box::new(train)))
+            }
+            Some(EventType::HypeTrainEnd) => {
+                parse(version, &event, |train| Self::HypeTrainEnd(Box::new(train)))
+            }
+            Some(EventType::GoalBegin) => {
+                parse(version, &event, |goal| Self::GoalBegin(Box::new(goal)))
+            }
+            Some(EventType::GoalProgress) => {
+                parse(version, &event, |goal| Self::GoalProgress(Box::new(goal)))
+            }
+            Some(EventType::GoalEnd) => {
+                parse(version, &event, |goal| Self::GoalEnd(Box::new(goal)))
+            }
+            Some(EventType::CharityDonation) => {
+                parse(version, &event, |donation| Self::CharityDonation(Box::new(donation)))
+            }
+            Some(EventType::CharityCampaignStart) => {
+                parse(version, &event, |c| Self::CharityCampaignStart(Box::new(c)))
+            }
+            Some(EventType::CharityCampaignProgress) => {
+                parse(version, &event, |c| Self::CharityCampaignProgress(Box::new(c)))
+            }
+            Some(EventType::CharityCampaignStop) => {
+                parse(version, &event, |c| Self::CharityCampaignStop(Box::new(c)))
+            }
+            _ => None,
+        };
+
+        match parsed {
+            Some(Ok(parsed)) => parsed,
+            Some(Err(e)) => {
+                tracing::warn!("Failed to parse {sub_type} v{version} notification: {e}");
+                Self::Other(event)
+            }
+            None => Self::Other(event),
+        }
+    }
+
+    /// The event type callbacks for this event are registered under, `None`
+    /// for events the library doesn't parse.
+    #[must_use]
+    pub const fn event_type(&self) -> Option<EventType> {
+        match self {
+            Self::ChannelChatMessage(_) => Some(EventType::ChatMessage),
+            Self::ChannelFollow(_) => Some(EventType::ChannelFollow),
+            Self::ChannelSubscribe(_) => Some(EventType::Subscription),
+            Self::ChannelSubscriptionEnd(_) => Some(EventType::SubscriptionEnd),
+            Self::ChannelSubscriptionGift(_) => Some(EventType::SubscriptionGift),
+            Self::ChannelSubscriptionMessage(_) => Some(EventType::SubscriptionMessage),
+            Self::ChannelCheer(_) => Some(EventType::Bits),
+            Self::ChannelBitsUse(_) => Some(EventType::BitsUse),
+            Self::ChannelRaid(_) => Some(EventType::ChannelRaid),
+            Self::CustomRewardRedemptionAdd(_) => Some(EventType::RewardRedemptionAdd),
+            Self::CustomRewardRedemptionUpdate(_) => Some(EventType::RewardRedemptionUpdate),
+            Self::CustomRewardAdd(_) => Some(EventType::CustomRewardAdd),
+            Self::CustomRewardUpdate(_) => Some(EventType::CustomRewardUpdate),
+            Self::CustomRewardRemove(_) => Some(EventType::CustomRewardRemove),
+            Self::AutomaticRewardRedemptionAdd(_) => Some(EventType::AutomaticRewardRedemptionAdd),
+            Self::PollBegin(_) => Some(EventType::PollBegin),
+            Self::PollProgress(_) => Some(EventType::PollProgress),
+            Self::PollEnd(_) => Some(EventType::PollEnd),
+            Self::PredictionBegin(_) => Some(EventType::PredictionBegin),
+            Self::PredictionProgress(_) => Some(EventType::PredictionProgress),
+            Self::PredictionLock(_) => Some(EventType::PredictionLock),
+            Self::PredictionEnd(_) => Some(EventType::PredictionEnd),
+            Self::HypeTrainBegin(_) => Some(EventType::HypeTrainBegin),
+            Self::HypeTrainProgress(_) => Some(EventType::HypeTrainProgress),
+            Self::HypeTrainEnd(_) => Some(EventType::HypeTrainEnd),
+            Self::GoalBegin(_) => Some(EventType::GoalBegin),
+            Self::GoalProgress(_) => Some(EventType::GoalProgress),
+            Self::GoalEnd(_) => Some(EventType::GoalEnd),
+            Self::CharityDonation(_) => Some(EventType::CharityDonation),
+            Self::CharityCampaignStart(_) => Some(EventType::CharityCampaignStart),
+            Self::CharityCampaignProgress(_) => Some(EventType::CharityCampaignProgress),
+            Self::CharityCampaignStop(_) => Some(EventType::CharityCampaignStop),
+            Self::Other(_) => None,
+        }
+    }
+}
+
+/// Parses `event` into `T` and wraps it with `variant`, `None` if `T`
+/// doesn't support `version`.
+fn parse<T, F>(
+    version: &str,
+    event: &Value,
+    variant: F,
+) -> Option<serde_json::Result<NotificationEvent>>
+where
+    T: EventPayload + DeserializeOwned,
+    F: FnOnce(T) -> NotificationEvent, {
+    T::VERSIONS.contains(&version).then(|| T::deserialize(event).map(variant))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{Value, json};
+
+    use super::{EventType, NotificationEvent, SubscriptionType};
+
+    fn follow() -> Value {
+        json!({
+            "user_id": "1234",
+            "user_login": "cool_user",
+            "user_name": "Cool_User",
+            "broadcaster_user_id": "1337",
+            "broadcaster_user_login": "cooler_user",
+            "broadcaster_user_name": "Cooler_User",
+            "followed_at": "2020-07-15T18:16:11.17106713Z"
+        })
+    }
+
+    #[test]
+    fn parses_supported_version() {
+        let sub_type: SubscriptionType = EventType::ChannelFollow.into();
+        let event: NotificationEvent = NotificationEvent::from_value(&sub_type, "2", follow());
+
+        assert!(matches!(event, NotificationEvent::ChannelFollow(_)));
+        assert_eq!(event.event_type(), Some(EventType::ChannelFollow));
+    }
+
+    #[test]
+    fn unsupported_version_keeps_raw_event() {
+        let sub_type: SubscriptionType = EventType::ChannelFollow.into();
+        let event: NotificationEvent = NotificationEvent::from_value(&sub_type, "1", follow());
+
+        assert_eq!(event, NotificationEvent::Other(follow()));
+    }
+
+    #[test]
+    fn malformed_body_keeps_raw_event() {
+        let sub_type: SubscriptionType = EventType::ChannelFollow.into();
+        let body: Value = json!({ "user_id": 1234 });
+        let event: NotificationEvent = NotificationEvent::from_value(&sub_type, "2", body.clone());
+
+        assert_eq!(event, NotificationEvent::Other(body));
+    }
+
+    #[test]
+    fn unknown_type_keeps_raw_event() {
+        let sub_type: SubscriptionType = String::from("channel.brand_new").into();
+        let event: NotificationEvent = NotificationEvent::from_value(&sub_type, "1", follow());
+
+        assert_eq!(event, NotificationEvent::Other(follow()));
+        assert_eq!(event.event_type(), None);
+    }
+
+    #[test]
+    fn hype_train_v1_keeps_raw_event() {
+        let sub_type: SubscriptionType = EventType::HypeTrainBegin.into();
+        let body: Value = json!({
+            "id": "1b0AsbInCHZW2SQFQkCzqN07Ib2",
+            "broadcaster_user_id": "1337",
+            "broadcaster_user_login": "cool_user",
+            "broadcaster_user_name": "Cool_User",
+            "total": 137,
+            "progress": 137,
+            "goal": 500,
+            "top_contributions": [],
+            "last_contribution": {
+                "user_id": "123",
+                "user_login": "pogchamp",
+                "user_name": "PogChamp",
+                "type": "bits",
+                "total": 50
+            },
+            "level": 2,
+            "started_at": "2020-07-15T17:16:03.17106713Z",
+            "expires_at": "2020-07-15T17:16:11.17106713Z",
+            "is_golden_kappa_train": false
+        });
+        let event: NotificationEvent = NotificationEvent::from_value(&sub_type, "1", body.clone());
+
+        assert_eq!(event, NotificationEvent::Other(body));
+    }
+}
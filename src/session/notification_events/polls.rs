@@ -0,0 +1,361 @@
+use super::{DateTime, Deserialize, Utc};
+
+/// How a poll ended.
+#[derive(Deserialize, Debug, Clone, Copy, Hash, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum PollEndStatus {
+    /// The poll ended normally.
+    Completed,
+
+    /// The poll ended and was hidden from the channel.
+    Archived,
+
+    /// The poll was ended early by the broadcaster.
+    Terminated,
+
+    /// A status this crate doesn't know yet.
+    #[serde(other)]
+    Unknown,
+}
+
+/// A choice of a poll. The vote counts are only set by progress and end
+/// events.
+#[derive(Deserialize, Debug, Clone, Hash, PartialEq, Eq)]
+pub struct PollChoice {
+    /// ID for the choice.
+    pub id: String,
+
+    /// Text displayed for the choice.
+    pub title: String,
+
+    /// Not used, always `0` if set.
+    pub bits_votes: Option<u32>,
+
+    /// Number of votes received via Channel Points.
+    pub channel_points_votes: Option<u32>,
+
+    /// Total number of votes received for the choice across all methods of
+    /// voting.
+    pub votes: Option<u32>,
+}
+
+/// Whether a voting method of a poll is enabled, and what a vote costs.
+#[derive(Deserialize, Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub struct VotingSettings {
+    /// Indicates if the voting method is enabled.
+    pub is_enabled: bool,
+
+    /// Number of Bits or Channel Points required to vote once.
+    pub amount_per_vote: u32,
+}
+
+/// A `channel.poll.begin`, `.progress` or `.end` event, sent when a poll
+/// starts, receives votes or ends.
+#[derive(Deserialize, Debug, Clone, Hash, PartialEq, Eq)]
+pub struct Poll {
+    /// ID of the poll.
+    pub id: String,
+
+    /// The requested broadcaster ID.
+    pub broadcaster_user_id: String,
+
+    /// The requested broadcaster login.
+    pub broadcaster_user_login: String,
+
+    /// The requested broadcaster display name.
+    pub broadcaster_user_name: String,
+
+    /// Question displayed for the poll.
+    pub title: String,
+
+    /// The choices of the poll, with vote counts for progress and end
+    /// events.
+    pub choices: Vec<PollChoice>,
+
+    /// Not supported.
+    pub bits_voting: VotingSettings,
+
+    /// The Channel Points voting settings for the poll.
+    pub channel_points_voting: VotingSettings,
+
+    /// The time the poll started.
+    #[serde(deserialize_with = "super::from_rfc3339")]
+    pub started_at: DateTime<Utc>,
+
+    /// The time the poll will end, set by begin and progress events.
+    #[serde(default)]
+    pub ends_at: Option<DateTime<Utc>>,
+
+    /// The status of the poll, set by end events.
+    pub status: Option<PollEndStatus>,
+
+    /// The time the poll ended, set by end events.
+    #[serde(default)]
+    pub ended_at: Option<DateTime<Utc>>,
+}
+
+/// How a prediction ended.
+#[derive(Deserialize, Debug, Clone, Copy, Hash, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum PredictionEndStatus {
+    /// A winning outcome was chosen and the points were paid out.
+    Resolved,
+
+    /// The prediction was canceled and the points were refunded.
+    Canceled,
+
+    /// A status this crate doesn't know yet.
+    #[serde(other)]
+    Unknown,
+}
+
+/// The color of a prediction outcome.
+#[derive(Deserialize, Debug, Clone, Copy, Hash, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum OutcomeColor {
+    Blue,
+    Pink,
+
+    /// A color this crate doesn't know yet.
+    #[serde(other)]
+    Unknown,
+}
+
+/// A user who predicted one of the outcomes with many Channel Points.
+#[derive(Deserialize, Debug, Clone, Hash, PartialEq, Eq)]
+pub struct TopPredictor {
+    /// The ID of the user.
+    pub user_id: String,
+
+    /// The login of the user.
+    pub user_login: String,
+
+    /// The display name of the user.
+    pub user_name: String,
+
+    /// The number of Channel Points won. `None` until the prediction was
+    /// resolved, `0` if the user predicted the losing outcome.
+    pub channel_points_won: Option<u64>,
+
+    /// The number of Channel Points used to participate in the prediction.
+    pub channel_points_used: u64,
+}
+
+/// An outcome of a prediction. The counts and top predictors are only set
+/// by progress, lock and end events.
+#[derive(Deserialize, Debug, Clone, Hash, PartialEq, Eq)]
+pub struct PredictionOutcome {
+    /// The outcome ID.
+    pub id: String,
+
+    /// The outcome title.
+    pub title: String,
+
+    /// The color for the outcome.
+    pub color: OutcomeColor,
+
+    /// The number of users who used Channel Points on this outcome.
+    pub users: Option<u32>,
+
+    /// The total number of Channel Points used on this outcome.
+    pub channel_points: Option<u64>,
+
+    /// Up to 10 users who used the most Channel Points on this outcome.
+    #[serde(default)]
+    pub top_predictors: Option<Vec<TopPredictor>>,
+}
+
+/// A `channel.prediction.begin`, `.progress`, `.lock` or `.end` event, sent
+/// when a prediction starts, receives predictions, is locked or ends.
+#[derive(Deserialize, Debug, Clone, Hash, PartialEq, Eq)]
+pub struct Prediction {
+    /// Channel Points Prediction ID.
+    pub id: String,
+
+    /// The requested broadcaster ID.
+    pub broadcaster_user_id: String,
+
+    /// The requested broadcaster login.
+    pub broadcaster_user_login: String,
+
+    /// The requested broadcaster display name.
+    pub broadcaster_user_name: String,
+
+    /// Title for the Channel Points Prediction.
+    pub title: String,
+
+    /// The outcomes of the prediction.
+    pub outcomes: Vec<PredictionOutcome>,
+
+    /// ID of the winning outcome, set by end events of resolved predictions.
+    #[serde(default)]
+    pub winning_outcome_id: Option<String>,
+
+    /// The time the prediction started.
+    #[serde(deserialize_with = "super::from_rfc3339")]
+    pub started_at: DateTime<Utc>,
+
+    /// The time the prediction will automatically lock, set by begin and
+    /// progress events.
+    #[serde(default)]
+    pub locks_at: Option<DateTime<Utc>>,
+
+    /// The time the prediction was locked, set by lock events.
+    #[serde(default)]
+    pub locked_at: Option<DateTime<Utc>>,
+
+    /// The status of the prediction, set by end events.
+    pub status: Option<PredictionEndStatus>,
+
+    /// The time the prediction ended, set by end events.
+    #[serde(default)]
+    pub ended_at: Option<DateTime<Utc>>,
+}
+
+impl Prediction {
+    /// The winning outcome, once the prediction was resolved.
+    #[must_use]
+    pub fn winning_outcome(&self) -> Option<&PredictionOutcome> {
+        let winner: &str = self.winning_outcome_id.as_deref()?;
+        self.outcomes.iter().find(|outcome| outcome.id == winner)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::{
+        OutcomeColor, Poll, PollEndStatus, Prediction, PredictionEndStatus, PredictionOutcome,
+    };
+
+    #[test]
+    fn parses_poll_begin() {
+        let poll: Poll = serde_json::from_value(json!({
+            "id": "1243456",
+            "broadcaster_user_id": "1337",
+            "broadcaster_user_login": "cool_user",
+            "broadcaster_user_name": "Cool_User",
+            "title": "Aren't shoes just really hard socks?",
+            "choices": [
+                { "id": "123", "title": "Yeah!" },
+                { "id": "124", "title": "No!" }
+            ],
+            "bits_voting": { "is_enabled": true, "amount_per_vote": 10 },
+            "channel_points_voting": { "is_enabled": true, "amount_per_vote": 10 },
+            "started_at": "2020-07-15T17:16:03.17106713Z",
+            "ends_at": "2020-07-15T17:16:08.17106713Z"
+        }))
+        .unwrap();
+
+        assert_eq!(poll.choices.len(), 2);
+        assert_eq!(poll.choices[0].votes, None);
+        assert!(poll.ends_at.is_some());
+        assert_eq!(poll.status, None);
+    }
+
+    #[test]
+    fn parses_poll_end() {
+        let poll: Poll = serde_json::from_value(json!({
+            "id": "1243456",
+            "broadcaster_user_id": "1337",
+            "broadcaster_user_login": "cool_user",
+            "broadcaster_user_name": "Cool_User",
+            "title": "Aren't shoes just really hard socks?",
+            "choices": [
+                {
+                    "id": "123",
+                    "title": "Blue",
+                    "bits_votes": 0,
+                    "channel_points_votes": 50,
+                    "votes": 50
+                }
+            ],
+            "bits_voting": { "is_enabled": true, "amount_per_vote": 10 },
+            "channel_points_voting": { "is_enabled": true, "amount_per_vote": 10 },
+            "status": "completed",
+            "started_at": "2020-07-15T17:16:03.17106713Z",
+            "ended_at": "2020-07-15T17:16:11.17106713Z"
+        }))
+        .unwrap();
+
+        assert_eq!(poll.status, Some(PollEndStatus::Completed));
+        assert_eq!(poll.choices[0].votes, Some(50));
+    }
+
+    #[test]
+    fn parses_resolved_prediction() {
+        let prediction: Prediction = serde_json::from_value(json!({
+            "id": "1243456",
+            "broadcaster_user_id": "1337",
+            "broadcaster_user_login": "cool_user",
+            "broadcaster_user_name": "Cool_User",
+            "title": "Aren't shoes just really hard socks?",
+            "winning_outcome_id": "12345",
+            "outcomes": [
+                {
+                    "id": "12345",
+                    "title": "Yeah!",
+                    "color": "blue",
+                    "users": 2,
+                    "channel_points": 15000,
+                    "top_predictors": [
+                        {
+                            "user_name": "Cool_User",
+                            "user_login": "cool_user",
+                            "user_id": "1234",
+                            "channel_points_won": 10000,
+                            "channel_points_used": 500
+                        }
+                    ]
+                },
+                {
+                    "id": "22435",
+                    "title": "No!",
+                    "users": 2,
+                    "channel_points": 200,
+                    "color": "pink",
+                    "top_predictors": [
+                        {
+                            "user_name": "Cooler_User",
+                            "user_login": "cooler_user",
+                            "user_id": "12345",
+                            "channel_points_won": null,
+                            "channel_points_used": 100
+                        }
+                    ]
+                }
+            ],
+            "status": "resolved",
+            "started_at": "2020-07-15T17:16:03.17106713Z",
+            "ended_at": "2020-07-15T17:16:11.17106713Z"
+        }))
+        .unwrap();
+
+        let winner: &PredictionOutcome = prediction.winning_outcome().unwrap();
+        assert_eq!(prediction.status, Some(PredictionEndStatus::Resolved));
+        assert_eq!(winner.color, OutcomeColor::Blue);
+        assert_eq!(winner.channel_points, Some(15000));
+    }
+
+    #[test]
+    fn unresolved_prediction_has_no_winner() {
+        let prediction: Prediction = serde_json::from_value(json!({
+            "id": "1243456",
+            "broadcaster_user_id": "1337",
+            "broadcaster_user_login": "cool_user",
+            "broadcaster_user_name": "Cool_User",
+            "title": "Aren't shoes just really hard socks?",
+            "outcomes": [
+                { "id": "1243456", "title": "Yeah!", "color": "blue" },
+                { "id": "2243456", "title": "No!", "color": "pink" }
+            ],
+            "started_at": "2020-07-15T17:16:03.17106713Z",
+            "locks_at": "2020-07-15T17:21:03.17106713Z"
+        }))
+        .unwrap();
+
+        assert!(prediction.winning_outcome().is_none());
+        assert!(prediction.locks_at.is_some());
+    }
+}
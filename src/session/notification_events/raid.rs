@@ -0,0 +1,61 @@
+use super::Deserialize;
+
+/// A `channel.raid` event, sent when a broadcaster raids another
+/// broadcaster's channel. Whether it is an incoming or outgoing raid depends
+/// on the condition of the subscription.
+#[derive(Deserialize, Debug, Clone, Hash, PartialEq, Eq)]
+pub struct ChannelRaid {
+    /// The broadcaster ID that created the raid.
+    pub from_broadcaster_user_id: String,
+
+    /// The broadcaster login that created the raid.
+    pub from_broadcaster_user_login: String,
+
+    /// The broadcaster display name that created the raid.
+    pub from_broadcaster_user_name: String,
+
+    /// The broadcaster ID that received the raid.
+    pub to_broadcaster_user_id: String,
+
+    /// The broadcaster login that received the raid.
+    pub to_broadcaster_user_login: String,
+
+    /// The broadcaster display name that received the raid.
+    pub to_broadcaster_user_name: String,
+
+    /// The number of viewers in the raid.
+    pub viewers: u32,
+}
+
+impl ChannelRaid {
+    /// Whether the raid goes into `broadcaster_user_id`'s channel.
+    #[must_use]
+    pub fn is_incoming(&self, broadcaster_user_id: &str) -> bool {
+        self.to_broadcaster_user_id == broadcaster_user_id
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::ChannelRaid;
+
+    #[test]
+    fn parses_twitch_sample() {
+        let raid: ChannelRaid = serde_json::from_value(json!({
+            "from_broadcaster_user_id": "1234",
+            "from_broadcaster_user_login": "cool_user",
+            "from_broadcaster_user_name": "Cool_User",
+            "to_broadcaster_user_id": "1337",
+            "to_broadcaster_user_login": "cooler_user",
+            "to_broadcaster_user_name": "Cooler_User",
+            "viewers": 9001
+        }))
+        .unwrap();
+
+        assert_eq!(raid.viewers, 9001);
+        assert!(raid.is_incoming("1337"));
+        assert!(!raid.is_incoming("1234"));
+    }
+}
@@ -0,0 +1,269 @@
+use super::{Deserialize, Emote};
+
+/// The tier of a subscription.
+#[derive(Deserialize, Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum SubscriptionTier {
+    /// Tier 1, also used for Prime subscriptions.
+    #[serde(rename = "1000")]
+    Tier1,
+
+    /// Tier 2.
+    #[serde(rename = "2000")]
+    Tier2,
+
+    /// Tier 3.
+    #[serde(rename = "3000")]
+    Tier3,
+
+    /// A tier this crate doesn't know yet.
+    #[serde(other)]
+    Unknown,
+}
+
+/// A `channel.subscribe` event, sent when a user subscribes to the
+/// broadcaster. Resubscriptions are sent as `channel.subscription.message`
+/// instead.
+#[derive(Deserialize, Debug, Clone, Hash, PartialEq, Eq)]
+pub struct ChannelSubscribe {
+    /// The user ID for the user who subscribed to the specified channel.
+    pub user_id: String,
+
+    /// The user login for the user who subscribed to the specified channel.
+    pub user_login: String,
+
+    /// The user display name for the user who subscribed to the specified
+    /// channel.
+    pub user_name: String,
+
+    /// The requested broadcaster ID.
+    pub broadcaster_user_id: String,
+
+    /// The requested broadcaster login.
+    pub broadcaster_user_login: String,
+
+    /// The requested broadcaster display name.
+    pub broadcaster_user_name: String,
+
+    /// The tier of the subscription.
+    pub tier: SubscriptionTier,
+
+    /// Whether the subscription is a gift.
+    pub is_gift: bool,
+}
+
+/// A `channel.subscription.end` event, sent when a subscription to the
+/// broadcaster expires.
+#[derive(Deserialize, Debug, Clone, Hash, PartialEq, Eq)]
+pub struct ChannelSubscriptionEnd {
+    /// The user ID for the user whose subscription ended.
+    pub user_id: String,
+
+    /// The user login for the user whose subscription ended.
+    pub user_login: String,
+
+    /// The user display name for the user whose subscription ended.
+    pub user_name: String,
+
+    /// The broadcaster user ID.
+    pub broadcaster_user_id: String,
+
+    /// The broadcaster login.
+    pub broadcaster_user_login: String,
+
+    /// The broadcaster display name.
+    pub broadcaster_user_name: String,
+
+    /// The tier of the subscription that ended.
+    pub tier: SubscriptionTier,
+
+    /// Whether the subscription was a gift.
+    pub is_gift: bool,
+}
+
+/// A `channel.subscription.gift` event, sent when a user gives one or more
+/// subscriptions in the broadcaster's channel.
+#[derive(Deserialize, Debug, Clone, Hash, PartialEq, Eq)]
+pub struct ChannelSubscriptionGift {
+    /// The user ID of the user who sent the subscription gift. `None` if it
+    /// was an anonymous subscription gift.
+    pub user_id: Option<String>,
+
+    /// The user login of the user who sent the gift. `None` if it was an
+    /// anonymous subscription gift.
+    pub user_login: Option<String>,
+
+    /// The user display name of the user who sent the gift. `None` if it was
+    /// an anonymous subscription gift.
+    pub user_name: Option<String>,
+
+    /// The broadcaster user ID.
+    pub broadcaster_user_id: String,
+
+    /// The broadcaster login.
+    pub broadcaster_user_login: String,
+
+    /// The broadcaster display name.
+    pub broadcaster_user_name: String,
+
+    /// The number of subscriptions in the subscription gift.
+    pub total: u32,
+
+    /// The tier of subscriptions in the subscription gift.
+    pub tier: SubscriptionTier,
+
+    /// The number of subscriptions gifted by this user in the channel. `None`
+    /// for anonymous gifts or if the gifter has opted out of sharing this
+    /// information.
+    pub cumulative_total: Option<u32>,
+
+    /// Whether the subscription gift was anonymous.
+    pub is_anonymous: bool,
+}
+
+/// An emote in the text of a resubscription message.
+#[derive(Deserialize, Debug, Clone, Hash, PartialEq, Eq)]
+pub struct SubscriptionEmote {
+    /// The index of where the emote starts in the text.
+    pub begin: u32,
+
+    /// The index of where the emote ends in the text.
+    pub end: u32,
+
+    /// The emote. Only its ID is known, the other fields are empty.
+    #[serde(flatten)]
+    pub emote: Emote,
+}
+
+/// The message a user shared with their resubscription.
+#[derive(Deserialize, Debug, Clone, Hash, PartialEq, Eq)]
+pub struct SubscriptionMessageText {
+    /// The text of the resubscription chat message.
+    pub text: String,
+
+    /// The emotes in the text, `None` if there are none.
+    pub emotes: Option<Vec<SubscriptionEmote>>,
+}
+
+/// A `channel.subscription.message` event, sent when a user shares a
+/// resubscription in the broadcaster's channel.
+#[derive(Deserialize, Debug, Clone, Hash, PartialEq, Eq)]
+pub struct ChannelSubscriptionMessage {
+    /// The user ID of the user who sent a resubscription chat message.
+    pub user_id: String,
+
+    /// The user login of the user who sent a resubscription chat message.
+    pub user_login: String,
+
+    /// The user display name of the user who sent a resubscription chat
+    /// message.
+    pub user_name: String,
+
+    /// The broadcaster user ID.
+    pub broadcaster_user_id: String,
+
+    /// The broadcaster login.
+    pub broadcaster_user_login: String,
+
+    /// The broadcaster display name.
+    pub broadcaster_user_name: String,
+
+    /// The tier of the user's subscription.
+    pub tier: SubscriptionTier,
+
+    /// An object that contains the resubscription message and emote
+    /// information needed to recreate the message.
+    pub message: SubscriptionMessageText,
+
+    /// The total number of months the user has been subscribed to the
+    /// channel.
+    pub cumulative_months: u32,
+
+    /// The number of consecutive months the user's current subscription has
+    /// been active. `None` if the user has opted out of sharing this
+    /// information.
+    pub streak_months: Option<u32>,
+
+    /// The month duration of the subscription.
+    pub duration_months: u32,
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::{
+        ChannelSubscribe, ChannelSubscriptionGift, ChannelSubscriptionMessage, SubscriptionEmote,
+        SubscriptionTier,
+    };
+
+    #[test]
+    fn parses_subscribe() {
+        let sub: ChannelSubscribe = serde_json::from_value(json!({
+            "user_id": "1234",
+            "user_login": "cool_user",
+            "user_name": "Cool_User",
+            "broadcaster_user_id": "1337",
+            "broadcaster_user_login": "cooler_user",
+            "broadcaster_user_name": "Cooler_User",
+            "tier": "1000",
+            "is_gift": false
+        }))
+        .unwrap();
+
+        assert_eq!(sub.tier, SubscriptionTier::Tier1);
+        assert!(!sub.is_gift);
+    }
+
+    #[test]
+    fn parses_anonymous_gift() {
+        let gift: ChannelSubscriptionGift = serde_json::from_value(json!({
+            "user_id": null,
+            "user_login": null,
+            "user_name": null,
+            "broadcaster_user_id": "1337",
+            "broadcaster_user_login": "cooler_user",
+            "broadcaster_user_name": "Cooler_User",
+            "total": 2,
+            "tier": "2000",
+            "cumulative_total": null,
+            "is_anonymous": true
+        }))
+        .unwrap();
+
+        assert_eq!(gift.tier, SubscriptionTier::Tier2);
+        assert_eq!(gift.user_id, None);
+        assert_eq!(gift.total, 2);
+    }
+
+    #[test]
+    fn parses_message_with_emotes() {
+        let msg: ChannelSubscriptionMessage = serde_json::from_value(json!({
+            "user_id": "1234",
+            "user_login": "cool_user",
+            "user_name": "Cool_User",
+            "broadcaster_user_id": "1337",
+            "broadcaster_user_login": "cooler_user",
+            "broadcaster_user_name": "Cooler_User",
+            "tier": "3000",
+            "message": {
+                "text": "Love the stream! FevziGG",
+                "emotes": [{ "begin": 23, "end": 30, "id": "302976485" }]
+            },
+            "cumulative_months": 15,
+            "streak_months": 1,
+            "duration_months": 6
+        }))
+        .unwrap();
+
+        let emotes: Vec<SubscriptionEmote> = msg.message.emotes.unwrap();
+        assert_eq!(msg.tier, SubscriptionTier::Tier3);
+        assert_eq!(emotes[0].emote.id, "302976485");
+        assert_eq!(emotes[0].begin, 23);
+    }
+
+    #[test]
+    fn unknown_tier() {
+        let tier: SubscriptionTier = serde_json::from_value(json!("4000")).unwrap();
+        assert_eq!(tier, SubscriptionTier::Unknown);
+    }
+}
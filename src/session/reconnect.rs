@@ -1,7 +1,7 @@
 use super::{BaseMetadata, DateTime, Deserialize, MessageId, Utc};
 
 /// An object that identifies the message.
-#[derive(Deserialize, Debug, Hash, PartialEq, Eq)]
+#[derive(Deserialize, Debug, Clone, Hash, PartialEq, Eq)]
 #[allow(clippy::struct_field_names)]
 pub struct ReconnectMetadata {
     #[serde(deserialize_with = "super::deserialize_message_id")]
@@ -19,7 +19,7 @@ pub struct ReconnectMetadata {
 }
 
 /// An object that contains information about the connection.
-#[derive(Deserialize, Debug, Hash, PartialEq, Eq)]
+#[derive(Deserialize, Debug, Clone, Hash, PartialEq, Eq)]
 pub struct ReconnectSession {
     /// An ID that uniquely identifies this WebSocket connection.
     pub id: String,
@@ -37,12 +37,12 @@ pub struct ReconnectSession {
 }
 
 /// An object that contains the message.
-#[derive(Deserialize, Debug, Hash, PartialEq, Eq)]
+#[derive(Deserialize, Debug, Clone, Hash, PartialEq, Eq)]
 pub struct ReconnectPayload {
     pub session: ReconnectSession,
 }
 
-#[derive(Deserialize, Debug, Hash, PartialEq, Eq)]
+#[derive(Deserialize, Debug, Clone, Hash, PartialEq, Eq)]
 pub struct ReconnectMessage {
     pub metadata: ReconnectMetadata,
     pub payload: ReconnectPayload,
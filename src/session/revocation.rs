@@ -1,6 +1,6 @@
-use super::{BaseMetadata, DateTime, Deserialize, MessageId, Subscription, Utc};
+use super::{BaseMetadata, DateTime, Deserialize, MessageId, Subscription, SubscriptionStatus, Utc};
 
-#[derive(Debug, Deserialize, Hash, PartialEq, Eq)]
+#[derive(Debug, Clone, Deserialize, Hash, PartialEq, Eq)]
 pub struct RevocationMetadata {
     /// An ID that uniquely identifies the message. Twitch sends messages at
     /// least once, but if Twitch is unsure of whether you received a
@@ -25,13 +25,78 @@ pub struct RevocationMetadata {
     pub subscription_version: String,
 }
 
-#[derive(Debug, Deserialize, Hash, PartialEq, Eq)]
+/// Why Twitch revoked a subscription, taken from the `status` of the revoked
+/// subscription.
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub enum RevocationReason {
+    /// The user in the condition no longer exists.
+    UserRemoved,
+    /// The user revoked the authorization token, or the password was changed.
+    AuthorizationRevoked,
+    /// The callback failed to respond in a timely manner too many times.
+    NotificationFailuresExceeded,
+    /// The subscription type and version are no longer supported.
+    VersionRemoved,
+    /// The moderator in the condition is no longer a moderator of the
+    /// channel.
+    ModeratorRemoved,
+    /// A status this crate doesn't know yet.
+    Unknown(String),
+}
+
+impl RevocationReason {
+    /// The reason for a subscription revoked with `status`. Statuses that
+    /// aren't a revocation reason become [`RevocationReason::Unknown`].
+    #[must_use]
+    pub fn from_status(status: &SubscriptionStatus) -> Self {
+        match status {
+            SubscriptionStatus::UserRemoved => Self::UserRemoved,
+            SubscriptionStatus::AuthorizationRevoked => Self::AuthorizationRevoked,
+            SubscriptionStatus::NotificationFailuresExceeded => Self::NotificationFailuresExceeded,
+            SubscriptionStatus::VersionRemoved => Self::VersionRemoved,
+            SubscriptionStatus::ModeratorRemoved => Self::ModeratorRemoved,
+            other => Self::Unknown(other.as_str().to_string()),
+        }
+    }
+
+    /// The subscription status this reason was taken from.
+    #[must_use]
+    pub fn status(&self) -> SubscriptionStatus {
+        match self {
+            Self::UserRemoved => SubscriptionStatus::UserRemoved,
+            Self::AuthorizationRevoked => SubscriptionStatus::AuthorizationRevoked,
+            Self::NotificationFailuresExceeded => SubscriptionStatus::NotificationFailuresExceeded,
+            Self::VersionRemoved => SubscriptionStatus::VersionRemoved,
+            Self::ModeratorRemoved => SubscriptionStatus::ModeratorRemoved,
+            Self::Unknown(status) => SubscriptionStatus::from(status.clone()),
+        }
+    }
+
+    /// Whether creating the subscription again can succeed, possibly after
+    /// refreshing the token. Removed users, moderators and versions need a
+    /// different subscription instead.
+    #[must_use]
+    pub const fn is_recoverable(&self) -> bool {
+        matches!(self, Self::AuthorizationRevoked | Self::NotificationFailuresExceeded)
+    }
+}
+
+impl std::fmt::Display for RevocationReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Unknown(status) => write!(f, "{status}"),
+            known => write!(f, "{}", known.status()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Hash, PartialEq, Eq)]
 pub struct RevocationPayload {
     /// An object that contains information about your subscription.
     pub subscription: Subscription,
 }
 
-#[derive(Debug, Deserialize, Hash, PartialEq, Eq)]
+#[derive(Debug, Clone, Deserialize, Hash, PartialEq, Eq)]
 pub struct RevocationMessage {
     /// An object that identifies the message.
     pub metadata: RevocationMetadata,
@@ -79,4 +144,33 @@ impl RevocationMessage {
     ) -> crate::prelude::Result<Self> {
         Ok(Self { metadata: metadata.try_into()?, payload })
     }
+
+    /// Why the subscription was revoked.
+    #[must_use]
+    pub fn reason(&self) -> RevocationReason {
+        RevocationReason::from_status(&self.payload.subscription.status)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RevocationReason, SubscriptionStatus};
+
+    #[test]
+    fn reason_round_trips_status() {
+        let revoked: SubscriptionStatus = String::from("authorization_revoked").into();
+        let reason: RevocationReason = RevocationReason::from_status(&revoked);
+
+        assert_eq!(reason, RevocationReason::AuthorizationRevoked);
+        assert_eq!(reason.status(), revoked);
+        assert_eq!(reason.to_string(), "authorization_revoked");
+    }
+
+    #[test]
+    fn other_statuses_are_unknown() {
+        let reason: RevocationReason = RevocationReason::from_status(&SubscriptionStatus::Enabled);
+
+        assert_eq!(reason, RevocationReason::Unknown(String::from("enabled")));
+        assert!(!reason.is_recoverable());
+    }
 }
@@ -0,0 +1,219 @@
+use super::{Deserialize, EventType};
+
+/// The status of an EventSub subscription. Statuses this crate doesn't know
+/// yet are kept as [`SubscriptionStatus::Unknown`].
+#[derive(Deserialize, Debug, Clone, Hash, PartialEq, Eq)]
+#[serde(from = "String")]
+pub enum SubscriptionStatus {
+    Enabled,
+    WebhookCallbackVerificationPending,
+    WebhookCallbackVerificationFailed,
+    NotificationFailuresExceeded,
+    AuthorizationRevoked,
+    ModeratorRemoved,
+    UserRemoved,
+    ChatUserBanned,
+    VersionRemoved,
+    BetaMaintenance,
+    WebsocketDisconnected,
+    WebsocketFailedPingPong,
+    WebsocketReceivedInboundTraffic,
+    WebsocketConnectionUnused,
+    WebsocketInternalError,
+    WebsocketNetworkTimeout,
+    WebsocketNetworkError,
+    WebsocketFailedToReconnect,
+    Unknown(String),
+}
+
+impl SubscriptionStatus {
+    /// The status as Twitch sends it, e.g. `websocket_disconnected`.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Enabled => "enabled",
+            Self::WebhookCallbackVerificationPending => "webhook_callback_verification_pending",
+            Self::WebhookCallbackVerificationFailed => "webhook_callback_verification_failed",
+            Self::NotificationFailuresExceeded => "notification_failures_exceeded",
+            Self::AuthorizationRevoked => "authorization_revoked",
+            Self::ModeratorRemoved => "moderator_removed",
+            Self::UserRemoved => "user_removed",
+            Self::ChatUserBanned => "chat_user_banned",
+            Self::VersionRemoved => "version_removed",
+            Self::BetaMaintenance => "beta_maintenance",
+            Self::WebsocketDisconnected => "websocket_disconnected",
+            Self::WebsocketFailedPingPong => "websocket_failed_ping_pong",
+            Self::WebsocketReceivedInboundTraffic => "websocket_received_inbound_traffic",
+            Self::WebsocketConnectionUnused => "websocket_connection_unused",
+            Self::WebsocketInternalError => "websocket_internal_error",
+            Self::WebsocketNetworkTimeout => "websocket_network_timeout",
+            Self::WebsocketNetworkError => "websocket_network_error",
+            Self::WebsocketFailedToReconnect => "websocket_failed_to_reconnect",
+            Self::Unknown(status) => status,
+        }
+    }
+
+    /// Whether the subscription still delivers events.
+    #[must_use]
+    pub const fn is_enabled(&self) -> bool { matches!(self, Self::Enabled) }
+
+    /// Whether the subscription belonged to a WebSocket session that is gone.
+    /// Such subscriptions still count against the subscription limit until
+    /// Twitch removes them.
+    #[must_use]
+    pub const fn is_websocket_disconnected(&self) -> bool {
+        matches!(
+            self,
+            Self::WebsocketDisconnected
+                | Self::WebsocketFailedPingPong
+                | Self::WebsocketReceivedInboundTraffic
+                | Self::WebsocketConnectionUnused
+                | Self::WebsocketInternalError
+                | Self::WebsocketNetworkTimeout
+                | Self::WebsocketNetworkError
+                | Self::WebsocketFailedToReconnect
+        )
+    }
+}
+
+impl From<String> for SubscriptionStatus {
+    fn from(status: String) -> Self {
+        match status.as_str() {
+            "enabled" => Self::Enabled,
+            "webhook_callback_verification_pending" => Self::WebhookCallbackVerificationPending,
+            "webhook_callback_verification_failed" => Self::WebhookCallbackVerificationFailed,
+            "notification_failures_exceeded" => Self::NotificationFailuresExceeded,
+            "authorization_revoked" => Self::AuthorizationRevoked,
+            "moderator_removed" => Self::ModeratorRemoved,
+            "user_removed" => Self::UserRemoved,
+            "chat_user_banned" => Self::ChatUserBanned,
+            "version_removed" => Self::VersionRemoved,
+            "beta_maintenance" => Self::BetaMaintenance,
+            "websocket_disconnected" => Self::WebsocketDisconnected,
+            "websocket_failed_ping_pong" => Self::WebsocketFailedPingPong,
+            "websocket_received_inbound_traffic" => Self::WebsocketReceivedInboundTraffic,
+            "websocket_connection_unused" => Self::WebsocketConnectionUnused,
+            "websocket_internal_error" => Self::WebsocketInternalError,
+            "websocket_network_timeout" => Self::WebsocketNetworkTimeout,
+            "websocket_network_error" => Self::WebsocketNetworkError,
+            "websocket_failed_to_reconnect" => Self::WebsocketFailedToReconnect,
+            _ => Self::Unknown(status),
+        }
+    }
+}
+
+impl std::fmt::Display for SubscriptionStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// The type of an EventSub subscription, e.g. `channel.chat.message`. Types
+/// without an [`EventType`] are kept as [`SubscriptionType::Unknown`].
+#[derive(Deserialize, Debug, Clone, Hash, PartialEq, Eq)]
+#[serde(from = "String")]
+pub enum SubscriptionType {
+    Known(EventType),
+    Unknown(String),
+}
+
+impl SubscriptionType {
+    /// The type as Twitch sends it.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Known(event_type) => event_type.wire_name(),
+            Self::Unknown(sub_type) => sub_type,
+        }
+    }
+
+    /// The event type of this subscription type, if the crate knows it.
+    #[must_use]
+    pub const fn event_type(&self) -> Option<EventType> {
+        match self {
+            Self::Known(event_type) => Some(*event_type),
+            Self::Unknown(_) => None,
+        }
+    }
+
+    /// Whether `version` is a version of this type the crate knows.
+    #[must_use]
+    pub fn supports_version(&self, version: &SubscriptionVersion) -> bool {
+        self.event_type()
+            .is_some_and(|event_type| event_type.versions().contains(&version.as_str()))
+    }
+}
+
+impl From<String> for SubscriptionType {
+    fn from(sub_type: String) -> Self {
+        EventType::from_wire_name(&sub_type).map_or(Self::Unknown(sub_type), Self::Known)
+    }
+}
+
+impl From<EventType> for SubscriptionType {
+    fn from(event_type: EventType) -> Self { Self::Known(event_type) }
+}
+
+impl std::fmt::Display for SubscriptionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// The version of an EventSub subscription type. Versions this crate doesn't
+/// know yet are kept as [`SubscriptionVersion::Unknown`].
+#[derive(Deserialize, Debug, Clone, Hash, PartialEq, Eq)]
+#[serde(from = "String")]
+pub enum SubscriptionVersion {
+    V1,
+    V2,
+    Beta,
+    Unknown(String),
+}
+
+impl SubscriptionVersion {
+    /// The version as Twitch sends it, e.g. `2`.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::V1 => "1",
+            Self::V2 => "2",
+            Self::Beta => "beta",
+            Self::Unknown(version) => version,
+        }
+    }
+}
+
+impl From<String> for SubscriptionVersion {
+    fn from(version: String) -> Self {
+        match version.as_str() {
+            "1" => Self::V1,
+            "2" => Self::V2,
+            "beta" => Self::Beta,
+            _ => Self::Unknown(version),
+        }
+    }
+}
+
+impl std::fmt::Display for SubscriptionVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{EventType, SubscriptionType, SubscriptionVersion};
+
+    #[test]
+    fn parses_type_version_pairs() {
+        let follow: SubscriptionType = String::from("channel.follow").into();
+        let unknown: SubscriptionType = String::from("channel.brand_new").into();
+
+        assert_eq!(follow, SubscriptionType::Known(EventType::ChannelFollow));
+        assert!(follow.supports_version(&String::from("2").into()));
+        assert!(!follow.supports_version(&SubscriptionVersion::V1));
+        assert!(!unknown.supports_version(&SubscriptionVersion::V1));
+        assert_eq!(SubscriptionVersion::from(String::from("3")).as_str(), "3");
+    }
+}
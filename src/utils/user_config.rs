@@ -1,11 +1,14 @@
 use crate::prelude::Result;
 
-#[derive(Debug, Default)]
+#[derive(Debug, Clone, Default)]
 pub struct UserConfig {
     pub client_id: String,
     pub user_token: String,
     pub broadcaster_id: String,
     pub user_id: String,
+    /// The scopes granted to `user_token`, `None` if not known. Known scopes
+    /// let subscriptions the token can't create fail without a request.
+    pub scopes: Option<Vec<String>>,
 }
 
 impl UserConfig {
@@ -23,7 +26,7 @@ impl UserConfig {
         let broadcaster_id: String = std::env::var("BROADCASTER_ID")?;
         let user_id: String = std::env::var("USER_ID")?;
 
-        Ok(Self { client_id, user_token, broadcaster_id, user_id })
+        Ok(Self { client_id, user_token, broadcaster_id, user_id, scopes: None })
     }
 
     /// This function lets you create a default instance of `UserConfig`